@@ -1,5 +1,3 @@
-#![feature(mixed_integer_ops)]
-
 use benches::bench_overflowing_add_unsigned;
 
 bench_overflowing_add_unsigned! {
@@ -1,5 +1,3 @@
-#![feature(mixed_integer_ops)]
-
 use benches::bench_wrapping_sub_unsigned;
 
 bench_wrapping_sub_unsigned! {
@@ -1,5 +1,3 @@
-#![feature(mixed_integer_ops)]
-
 use benches::bench_wrapping_add_signed;
 
 bench_wrapping_add_signed! {
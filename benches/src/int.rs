@@ -75,8 +75,6 @@ macro_rules! bench_int_overflowing_sub {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_wrapping_add_unsigned! {
 ///     { i8, u8, i8, ConstrainedI8 },
 /// }
@@ -95,8 +93,6 @@ macro_rules! bench_wrapping_add_unsigned {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_overflowing_add_unsigned! {
 ///     { i8, u8, i8, ConstrainedI8 },
 /// }
@@ -115,8 +111,6 @@ macro_rules! bench_overflowing_add_unsigned {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_wrapping_sub_unsigned! {
 ///     { i8, u8, i8, ConstrainedI8 },
 /// }
@@ -135,8 +129,6 @@ macro_rules! bench_wrapping_sub_unsigned {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_overflowing_sub_unsigned! {
 ///     { i8, u8, i8, ConstrainedI8 },
 /// }
@@ -75,8 +75,6 @@ macro_rules! bench_uint_overflowing_sub {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_wrapping_add_signed! {
 ///     { u8, i8, u8, ConstrainedU8 },
 /// }
@@ -95,8 +93,6 @@ macro_rules! bench_wrapping_add_signed {
 /// # Example
 ///
 /// ```
-/// #![feature(mixed_integer_ops)]
-///
 /// benches::bench_overflowing_add_signed! {
 ///     { u8, i8, u8, ConstrainedU8 },
 /// }
@@ -0,0 +1,72 @@
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+
+use crate::sealed::Sealed;
+
+/// A common interface for all `Constrained` types.
+///
+/// This trait exposes the range's bounds and a subset of each type's inherent
+/// APIs, so code can be written once for any `Constrained` type, regardless of
+/// its primitive integer. It is only implemented for `Constrained` types whose
+/// parameters satisfy the construction constraints, and it is sealed, so it
+/// can't be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// use constrained_int::BoundedInt;
+/// use constrained_int::i8::ConstrainedI8;
+/// use constrained_int::u128::ConstrainedU128;
+///
+/// // Increments the value by one, stopping at the range's upper bound.
+/// fn bump<T: BoundedInt>(value: &mut T, one: T::Int) {
+///     *value = value.saturating_add(one);
+/// }
+///
+/// let mut small = ConstrainedI8::<-5, 10>::new_max();
+/// bump(&mut small, 1);
+/// assert_eq!(small.get(), 10);
+///
+/// let mut large = ConstrainedU128::<1, 100>::new_min();
+/// bump(&mut large, 1);
+/// assert_eq!(large.get(), 2);
+/// ```
+pub trait BoundedInt: Sealed + Copy + Eq + Ord + Hash + Debug + Display {
+    /// The primitive integer that backs the `Constrained` type.
+    type Int: Copy + Eq + Ord + Hash + Debug + Display;
+
+    /// The error returned by the type's fallible construction and assignment.
+    type Error: Copy + Eq + Debug + Display;
+
+    /// The minimum **inclusive** value that this type can hold.
+    const MIN: Self::Int;
+
+    /// The maximum **inclusive** value that this type can hold.
+    const MAX: Self::Int;
+
+    /// The value used by the type's `Default` implementation.
+    const DEF: Self::Int;
+
+    /// Creates a new instance with provided value, if it satifies the range's
+    /// inclusive bounds. See each type's inherent `new` for more information.
+    fn new(value: Self::Int) -> Result<Self, Self::Error>;
+
+    /// Returns the value of the contained integer type.
+    fn get(&self) -> Self::Int;
+
+    /// Sets the contained value, if it satifies the range's inclusive bounds. See
+    /// each type's inherent `set` for more information.
+    fn set(&mut self, value: Self::Int) -> Result<(), Self::Error>;
+
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if the
+    /// result is out of the range's inclusive bounds.
+    fn checked_add(self, rhs: Self::Int) -> Option<Self>;
+
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the
+    /// range's bounds.
+    fn saturating_add(self, rhs: Self::Int) -> Self;
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around the
+    /// range's bounds.
+    fn wrapping_add(self, rhs: Self::Int) -> Self;
+}
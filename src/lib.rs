@@ -11,7 +11,9 @@
 //! The `constrained_int` crate relies on the incomplete [`generic_const_exprs`]
 //! feature to define compile time constraints. Therefore, this crate can only
 //! be compiled with nightly and, more importantly, must be considered as an
-//! **experimental** crate only.
+//! **experimental** crate only. Some trait implementations that are guarded by
+//! these constraints, like `TryFrom` and `FromStr`, may also require enabling the
+//! feature in the dependent crate.
 //!
 //! This crate is `no_std` by default. See features section for more information.
 //!
@@ -49,6 +51,46 @@
 //! # Ok::<(), constrained_int::i8::ConstrainedI8Error<-5, 10>>(())
 //! ```
 //!
+//! ## Conversions
+//!
+//! A `Constrained` type can be converted into a `Constrained` type of another
+//! primitive with `From`, as long as the source range is contained by the target
+//! range. This condition is checked at compile time. Otherwise, convert it with
+//! `try_convert`, which returns the target's error if the value is out of the
+//! target range's bounds. `TryFrom` is implemented for all primitive integers.
+//!
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(generic_const_exprs)]
+//! use constrained_int::u8::ConstrainedU8;
+//! use constrained_int::i16::ConstrainedI16;
+//!
+//! let constrained = ConstrainedU8::<1, 100>::new_max();
+//!
+//! // Source range is contained by the target range.
+//! let widened: ConstrainedI16<-5, 100> = constrained.into();
+//! assert_eq!(widened.get(), 100);
+//!
+//! // Source range is not contained, the conversion is fallible.
+//! type Narrow = ConstrainedI16<-5, 50>;
+//! let narrowed = constrained.try_convert::<Narrow>();
+//! assert_eq!(narrowed, Narrow::new(100));
+//!
+//! // Primitives of other widths and signedness are also fallible.
+//! assert_eq!(Narrow::try_from(50u64), Narrow::new(50));
+//! ```
+//!
+//! Conversions with `From` do not compile if the source range is not contained
+//! by the target range.
+//!
+//! ```compile_fail
+//! use constrained_int::u8::ConstrainedU8;
+//! use constrained_int::i16::ConstrainedI16;
+//!
+//! let constrained = ConstrainedU8::<1, 100>::new_max();
+//! let narrowed: ConstrainedI16<-5, 50> = constrained.into();
+//! ```
+//!
 //! ## Safety
 //!
 //! This crate uses `#![forbid(unsafe_code)]` to ensure everything is implemented
@@ -57,7 +99,7 @@
 //! ## Feature flags
 //!
 //! This crate does not provide any default features. The features that can be
-//! enabled are: `std` and `serde`.
+//! enabled are: `std`, `serde`, `num-traits` and `rand`.
 //!
 //! ### std
 //!
@@ -77,8 +119,27 @@
 //! implementation. See each desired type documentation for more information about
 //! these constraints.
 //!
+//! ### num-traits
+//!
+//! The `num-traits` feature implements [num-traits]' `Bounded`, `ToPrimitive`,
+//! `FromPrimitive`, `NumCast`, `CheckedAdd`, `CheckedSub`, `SaturatingAdd`,
+//! `SaturatingSub`, `WrappingAdd` and `WrappingSub` traits for all `Constrained`
+//! types. Conversions from other numbers return `None` if the value is not contained
+//! by the range. `Wrapping` and `Saturating` implement `Bounded` and the operation
+//! traits that match their semantics.
+//!
+//! ### rand
+//!
+//! The `rand` feature implements [rand]'s `Distribution` for the `Standard`
+//! distribution, sampling uniformly over the whole range, and `SampleUniform` for
+//! all `Constrained` types, so `Uniform` and `Rng::gen_range` sample uniformly
+//! between two `Constrained` values. Sampled values are always contained by the
+//! range, for all primitive widths, including `u128` and `i128`.
+//!
 //! [`generic_const_exprs`]: https://github.com/rust-lang/rust/issues/76560
 //! [`serde`]: https://docs.rs/serde/latest/serde/
+//! [num-traits]: https://docs.rs/num-traits/latest/num_traits/
+//! [rand]: https://docs.rs/rand/latest/rand/
 
 // No raw pointers here, maybe in another castle.
 #![forbid(unsafe_code)]
@@ -99,6 +160,10 @@
 // https://github.com/rust-lang/rust/issues/57349
 #![feature(const_mut_refs)]
 //
+// Tracking issue for `step_trait`:
+// https://github.com/rust-lang/rust/issues/42168
+#![feature(step_trait)]
+//
 // Tracking issue for `doc_cfg` and `doc_auto_cfg`feature:
 // https://github.com/rust-lang/rust/issues/43781.
 #![feature(doc_auto_cfg)]
@@ -111,6 +176,7 @@
 // Import:
 // - `constrained_uint_def_impl!`.
 // - `constrained_int_def_impl!`.
+// - `constrained_conversion_impl!`.
 // - `forward_ref_binop!`.
 // - `forward_ref_op_assign!`.
 #[macro_use]
@@ -120,15 +186,33 @@ mod macros;
 // - `forward_ref_binop!`.
 // - `forward_ref_op_assign!`.
 mod num;
-pub use num::{Saturating, Wrapping};
+pub use num::{Checked, Saturating, Strict, Wrapping};
+
+mod bounded;
+pub use bounded::BoundedInt;
+
+mod parse;
+pub use parse::ParseIntError;
+
+mod scale;
 
 mod sealed;
-use sealed::{Constraints, Guard};
+use sealed::{guard_contains, Constraints, Guard};
 
 #[cfg(feature = "serde")]
 #[doc(cfg(feature = "serde"))]
 mod deserialize;
 
+#[cfg(feature = "num-traits")]
+#[doc(cfg(feature = "num-traits"))]
+mod num_traits;
+
+#[cfg(feature = "rand")]
+#[doc(cfg(feature = "rand"))]
+mod uniform;
+#[cfg(feature = "rand")]
+pub use uniform::UniformConstrained;
+
 #[cfg(test)]
 mod proptest;
 
@@ -136,26 +220,46 @@ mod proptest;
 // default values for doc examples.
 //
 // Format:
-//  { uint, sint, uint_mod, sint_mod, TypeName, ErrorName, MinErrorName, MaxErrorName },+
+//  { uint, sint, uint_mod, sint_mod, TypeName, ErrorName, MinErrorName, MaxErrorName, ParseErrorName },+
 constrained_uint_def_impl! {
-    { u8, i8, u8, i8, ConstrainedU8, ConstrainedU8Error, MinU8Error, MaxU8Error },
-    { u16, i16, u16, i16, ConstrainedU16, ConstrainedU16Error, MinU16Error, MaxU16Error },
-    { u32, i32, u32, i32, ConstrainedU32, ConstrainedU32Error, MinU32Error, MaxU32Error },
-    { u64, i64, u64, i64, ConstrainedU64, ConstrainedU64Error, MinU64Error, Max64Error },
-    { u128, i128, u128, i128, ConstrainedU128, ConstrainedU128Error, Min128Error, Max128Error },
-    { usize, isize, usize, isize, ConstrainedUsize, ConstrainedUsizeError, MinUsizeError, MaxUsizeError },
+    { u8, i8, u8, i8, ConstrainedU8, ConstrainedU8Error, MinU8Error, MaxU8Error, ParseU8Error },
+    { u16, i16, u16, i16, ConstrainedU16, ConstrainedU16Error, MinU16Error, MaxU16Error, ParseU16Error },
+    { u32, i32, u32, i32, ConstrainedU32, ConstrainedU32Error, MinU32Error, MaxU32Error, ParseU32Error },
+    { u64, i64, u64, i64, ConstrainedU64, ConstrainedU64Error, MinU64Error, Max64Error, ParseU64Error },
+    { u128, i128, u128, i128, ConstrainedU128, ConstrainedU128Error, Min128Error, Max128Error, ParseU128Error },
+    { usize, isize, usize, isize, ConstrainedUsize, ConstrainedUsizeError, MinUsizeError, MaxUsizeError, ParseUsizeError },
 }
 
 // Define mods, containers, errors, tests and impls for signed integers with
 // default values for doc examples.
 //
 // Format:
-//  { sint, uint, sint_mod, uint_mod, TypeName, ErrorName, MinErrorName, MaxErrorName },+
+//  { sint, uint, sint_mod, uint_mod, TypeName, ErrorName, MinErrorName, MaxErrorName, ParseErrorName },+
 constrained_int_def_impl! {
-    { i8, u8, i8, u8, ConstrainedI8, ConstrainedI8Error, MinI8Error, MaxI8Error },
-    { i16, u16, i16, u16, ConstrainedI16, ConstrainedI16Error, MinI16Error, MaxI16Error },
-    { i32, u32, i32, u32, ConstrainedI32, ConstrainedI32Error, MinI32Error, MaxI32Error },
-    { i64, u64, i64, u64, ConstrainedI64, ConstrainedI64Error, MinI64Error, MaxI64Error },
-    { i128, u128, i128, u128, ConstrainedI128, ConstrainedI128Error, MinI128Error, MaxI128Error },
-    { isize, usize, isize, usize, ConstrainedIsize, ConstrainedIsizeError, MinIsizeError, MaxIsizeError },
+    { i8, u8, i8, u8, ConstrainedI8, ConstrainedI8Error, MinI8Error, MaxI8Error, ParseI8Error },
+    { i16, u16, i16, u16, ConstrainedI16, ConstrainedI16Error, MinI16Error, MaxI16Error, ParseI16Error },
+    { i32, u32, i32, u32, ConstrainedI32, ConstrainedI32Error, MinI32Error, MaxI32Error, ParseI32Error },
+    { i64, u64, i64, u64, ConstrainedI64, ConstrainedI64Error, MinI64Error, MaxI64Error, ParseI64Error },
+    { i128, u128, i128, u128, ConstrainedI128, ConstrainedI128Error, MinI128Error, MaxI128Error, ParseI128Error },
+    { isize, usize, isize, usize, ConstrainedIsize, ConstrainedIsizeError, MinIsizeError, MaxIsizeError, ParseIsizeError },
+}
+
+// Implement conversions between every pair of `Constrained` types and primitives
+// with different widths or signedness.
+//
+// Format:
+//  { int, int_mod, TypeName, ErrorName },+
+constrained_conversion_impl! {
+    { u8, u8, ConstrainedU8, ConstrainedU8Error },
+    { u16, u16, ConstrainedU16, ConstrainedU16Error },
+    { u32, u32, ConstrainedU32, ConstrainedU32Error },
+    { u64, u64, ConstrainedU64, ConstrainedU64Error },
+    { u128, u128, ConstrainedU128, ConstrainedU128Error },
+    { usize, usize, ConstrainedUsize, ConstrainedUsizeError },
+    { i8, i8, ConstrainedI8, ConstrainedI8Error },
+    { i16, i16, ConstrainedI16, ConstrainedI16Error },
+    { i32, i32, ConstrainedI32, ConstrainedI32Error },
+    { i64, i64, ConstrainedI64, ConstrainedI64Error },
+    { i128, i128, ConstrainedI128, ConstrainedI128Error },
+    { isize, isize, ConstrainedIsize, ConstrainedIsizeError },
 }
@@ -0,0 +1,130 @@
+// Implements in-place arithmetic APIs for `Constrained` types, forwarding to their
+// saturating, wrapping and fallible (`try_*`) counterparts.
+//
+// Each entry provides the rhs type, and the constructor and rhs used by the doc
+// examples, which must make the operation overflow the range's bounds.
+macro_rules! constrained_assign_impl {
+    (   $Int:ty, $md:ident, $Ty:ident, $Err:ident, $min:literal..=$max:literal,
+      $({ $Rhs:ty, $start:ident, $rhs:literal,
+          $sat_assign:ident => $sat_f:ident,
+          $wrap_assign:ident => $wrap_f:ident,
+          $check_assign:ident => $try_f:ident }),+ $(,)?
+    ) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {$(
+            #[doc = concat!("In-place version of [`", stringify!($sat_f), "`]. Same as `*self = self.", stringify!($sat_f), "(rhs)`.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let mut constrained = Constrained::", stringify!($start), "();")]
+            #[doc = concat!("constrained.", stringify!($sat_assign), "(", stringify!($rhs), ");")]
+            #[doc = concat!("assert_eq!(constrained, Constrained::", stringify!($start), "().",
+                stringify!($sat_f), "(", stringify!($rhs), "));")]
+            /// ```
+            ///
+            #[doc = concat!("[`", stringify!($sat_f), "`]: ", stringify!($Ty), "::", stringify!($sat_f))]
+            #[inline]
+            pub const fn $sat_assign(&mut self, rhs: $Rhs) {
+                *self = self.$sat_f(rhs);
+            }
+
+            #[doc = concat!("In-place version of [`", stringify!($wrap_f), "`]. Same as `*self = self.", stringify!($wrap_f), "(rhs)`.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let mut constrained = Constrained::", stringify!($start), "();")]
+            #[doc = concat!("constrained.", stringify!($wrap_assign), "(", stringify!($rhs), ");")]
+            #[doc = concat!("assert_eq!(constrained, Constrained::", stringify!($start), "().",
+                stringify!($wrap_f), "(", stringify!($rhs), "));")]
+            /// ```
+            ///
+            #[doc = concat!("[`", stringify!($wrap_f), "`]: ", stringify!($Ty), "::", stringify!($wrap_f))]
+            #[inline]
+            pub const fn $wrap_assign(&mut self, rhs: $Rhs) {
+                *self = self.$wrap_f(rhs);
+            }
+
+            #[doc = concat!("In-place version of [`", stringify!($try_f), "`]. If the result is out of the")]
+            /// range's inclusive bounds, an error is returned indicating which bound was
+            /// violated, and the value is left untouched.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let mut constrained = Constrained::", stringify!($start), "();")]
+            #[doc = concat!("assert!(constrained.", stringify!($check_assign), "(", stringify!($rhs), ").is_err());")]
+            #[doc = concat!("assert_eq!(constrained, Constrained::", stringify!($start), "());")]
+            /// ```
+            ///
+            #[doc = concat!("[`", stringify!($try_f), "`]: ", stringify!($Ty), "::", stringify!($try_f))]
+            #[inline]
+            pub fn $check_assign(&mut self, rhs: $Rhs) -> Result<(), $Err<MIN, MAX>> {
+                *self = self.$try_f(rhs)?;
+                Ok(())
+            }
+        )+}
+    };
+}
+
+// Implements tests for in-place arithmetic APIs of `Constrained` types, checking
+// them against their by value counterparts for every rhs of the provided range.
+#[cfg(test)]
+macro_rules! tests_assign {
+    (   $ty_path:path, $Ty:ident,
+      $({ $Rhs:ty, $rhs_range:expr,
+          $sat_assign:ident => $sat_f:ident,
+          $wrap_assign:ident => $wrap_f:ident,
+          $check_assign:ident => $try_f:ident }),+ $(,)?
+    ) => {
+        use $ty_path::*;
+
+        $(mod $check_assign {
+            use super::$Ty;
+
+            type CnstTest = $Ty<2, 9>;
+
+            #[test]
+            fn matches_by_value_apis() {
+                for value in CnstTest::range() {
+                    let cnst = CnstTest::new(value).unwrap();
+                    for rhs in $rhs_range {
+                        let rhs = rhs as $Rhs;
+
+                        let mut assigned = cnst;
+                        assigned.$sat_assign(rhs);
+                        assert_eq!(assigned, cnst.$sat_f(rhs));
+
+                        let mut assigned = cnst;
+                        assigned.$wrap_assign(rhs);
+                        assert_eq!(assigned, cnst.$wrap_f(rhs));
+
+                        let mut assigned = cnst;
+                        match cnst.$try_f(rhs) {
+                            Ok(expected) => {
+                                assert_eq!(assigned.$check_assign(rhs), Ok(()));
+                                assert_eq!(assigned, expected);
+                            }
+                            Err(err) => {
+                                assert_eq!(assigned.$check_assign(rhs), Err(err.into()));
+                                assert_eq!(assigned, cnst);
+                            }
+                        }
+                    }
+                }
+            }
+        })+
+    };
+}
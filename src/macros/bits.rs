@@ -0,0 +1,400 @@
+// Implements bitwise APIs for `Constrained` types, checking results against the
+// range's inclusive bounds.
+macro_rules! constrained_bits_impl {
+    ($Int:ty, $md:ident, $Ty:ident, $Err:ident) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Shifts the value left, discarding the bits shifted out of the primitive.
+            /// Shifting by `BITS` or more shifts out all bits.
+            #[must_use]
+            #[inline]
+            const fn shl_unbounded(value: $Int, rhs: u32) -> $Int {
+                match value.checked_shl(rhs) {
+                    Some(value) => value,
+                    None => 0,
+                }
+            }
+
+            /// Shifts the value right, arithmetically for signed integers. Shifting by
+            /// `BITS` or more shifts out all bits.
+            #[must_use]
+            #[inline]
+            const fn shr_unbounded(value: $Int, rhs: u32) -> $Int {
+                match value.checked_shr(rhs) {
+                    Some(value) => value,
+                    // Zero, or all bits set for negative values.
+                    None => (value >> (<$Int>::BITS - 1)) >> 1,
+                }
+            }
+
+            /// Checked bitwise AND. Computes `self & rhs`, returning [`None`] if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.checked_bitand(0b0011).map(|m| m.get()), Some(0b0010));
+            ///
+            /// // Below lower bound.
+            /// assert_eq!(mask.checked_bitand(0b0001), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_bitand(self, rhs: $Int) -> Option<Self> {
+                Self::checked_new_unguarded(self.0 & rhs)
+            }
+
+            /// Checked bitwise OR. Computes `self | rhs`, returning [`None`] if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.checked_bitor(0b1001).map(|m| m.get()), Some(0b1111));
+            ///
+            /// // Out of bounds.
+            /// assert_eq!(mask.checked_bitor(!0), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_bitor(self, rhs: $Int) -> Option<Self> {
+                Self::checked_new_unguarded(self.0 | rhs)
+            }
+
+            /// Checked bitwise XOR. Computes `self ^ rhs`, returning [`None`] if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.checked_bitxor(0b0011).map(|m| m.get()), Some(0b0101));
+            ///
+            /// // Out of bounds.
+            /// assert_eq!(mask.checked_bitxor(!0), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_bitxor(self, rhs: $Int) -> Option<Self> {
+                Self::checked_new_unguarded(self.0 ^ rhs)
+            }
+
+            /// Checked shift left. Computes `self << rhs`, returning [`None`] if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// Bits shifted out of the primitive are discarded. Unlike the primitive's
+            /// `checked_shl`, shifting by `BITS` or more is not an error by itself, it
+            /// shifts out all bits.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.checked_shl(1).map(|m| m.get()), Some(0b1100));
+            ///
+            /// // Out of bounds.
+            /// assert_eq!(mask.checked_shl(5), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+                Self::checked_new_unguarded(Self::shl_unbounded(self.0, rhs))
+            }
+
+            /// Checked shift right. Computes `self >> rhs`, returning [`None`] if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// The shift is arithmetic for signed integers. Unlike the primitive's
+            /// `checked_shr`, shifting by `BITS` or more is not an error by itself, it
+            /// shifts out all bits.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.checked_shr(1).map(|m| m.get()), Some(0b0011));
+            ///
+            /// // Below lower bound.
+            /// assert_eq!(mask.checked_shr(2), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+                Self::checked_new_unguarded(Self::shr_unbounded(self.0, rhs))
+            }
+
+            /// Fallible bitwise AND. Computes `self & rhs`, returning an error if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.try_bitand(0b0011)?.get(), 0b0010);
+            ///
+            /// // Below lower bound.
+            /// assert!(mask.try_bitand(0b0001).is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_bitand(self, rhs: $Int) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(self.0 & rhs)
+            }
+
+            /// Fallible bitwise OR. Computes `self | rhs`, returning an error if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.try_bitor(0b1001)?.get(), 0b1111);
+            ///
+            /// // Out of bounds.
+            /// assert!(mask.try_bitor(!0).is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_bitor(self, rhs: $Int) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(self.0 | rhs)
+            }
+
+            /// Fallible bitwise XOR. Computes `self ^ rhs`, returning an error if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.try_bitxor(0b0011)?.get(), 0b0101);
+            ///
+            /// // Out of bounds.
+            /// assert!(mask.try_bitxor(!0).is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_bitxor(self, rhs: $Int) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(self.0 ^ rhs)
+            }
+
+            /// Fallible shift left. Computes `self << rhs`, returning an error if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// Bits shifted out of the primitive are discarded, and shifting by `BITS`
+            /// or more shifts out all bits.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.try_shl(1)?.get(), 0b1100);
+            ///
+            /// // Out of bounds.
+            /// assert!(mask.try_shl(5).is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_shl(self, rhs: u32) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(Self::shl_unbounded(self.0, rhs))
+            }
+
+            /// Fallible shift right. Computes `self >> rhs`, returning an error if the
+            /// result is out of the range's inclusive bounds.
+            ///
+            /// The shift is arithmetic for signed integers, and shifting by `BITS` or
+            /// more shifts out all bits.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.try_shr(1)?.get(), 0b0011);
+            ///
+            /// // Below lower bound.
+            /// assert!(mask.try_shr(2).is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_shr(self, rhs: u32) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(Self::shr_unbounded(self.0, rhs))
+            }
+
+            /// Returns the number of ones in the binary representation of `self`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.count_ones(), 2);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn count_ones(self) -> u32 {
+                self.0.count_ones()
+            }
+
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            #[doc = concat!("assert_eq!(mask.leading_zeros(), ", stringify!($Int), "::BITS - 3);")]
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn leading_zeros(self) -> u32 {
+                self.0.leading_zeros()
+            }
+
+            /// Returns the number of trailing zeros in the binary representation of `self`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Mask = ", stringify!($Ty), "<2, 0b0111_1111>;")]
+            ///
+            /// let mask = Mask::new(0b0110)?;
+            /// assert_eq!(mask.trailing_zeros(), 1);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<2, 0b0111_1111>>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline(always)]
+            pub const fn trailing_zeros(self) -> u32 {
+                self.0.trailing_zeros()
+            }
+        }
+    };
+}
+
+// Implements tests for bitwise APIs of `Constrained` types.
+#[cfg(test)]
+macro_rules! tests_bits {
+    ($Int:ty, $ty_path:path, $Ty:ident, $Err:ident) => {
+        use $ty_path::*;
+
+        type MaskTest = $Ty<2, 0b0111_1111>;
+        type CnstTest = $Ty<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+        fn expected(value: $Int) -> Result<MaskTest, $Err<2, 0b0111_1111>> {
+            MaskTest::new(value)
+        }
+
+        #[test]
+        fn bitwise() {
+            for value in MaskTest::range() {
+                let mask = MaskTest::new(value).unwrap();
+                for rhs in [0, 1, 0b0101, 0b0111_1111, !0, <$Int>::MIN, <$Int>::MAX] {
+                    assert_eq!(mask.try_bitand(rhs), expected(value & rhs));
+                    assert_eq!(mask.try_bitor(rhs), expected(value | rhs));
+                    assert_eq!(mask.try_bitxor(rhs), expected(value ^ rhs));
+                    assert_eq!(mask.checked_bitand(rhs), expected(value & rhs).ok());
+                    assert_eq!(mask.checked_bitor(rhs), expected(value | rhs).ok());
+                    assert_eq!(mask.checked_bitxor(rhs), expected(value ^ rhs).ok());
+                }
+            }
+        }
+
+        #[test]
+        fn shifts_within_bits() {
+            for value in MaskTest::range() {
+                let mask = MaskTest::new(value).unwrap();
+                for rhs in 0..<$Int>::BITS {
+                    assert_eq!(mask.try_shl(rhs), expected(value << rhs));
+                    assert_eq!(mask.try_shr(rhs), expected(value >> rhs));
+                    assert_eq!(mask.checked_shl(rhs), expected(value << rhs).ok());
+                    assert_eq!(mask.checked_shr(rhs), expected(value >> rhs).ok());
+                }
+            }
+        }
+
+        #[test]
+        fn shifts_out_all_bits() {
+            type ZeroTest = $Ty<0, 0b0111_1111>;
+
+            let zero = ZeroTest::new_max();
+            assert_eq!(zero.checked_shl(<$Int>::BITS).map(|cnst| cnst.get()), Some(0));
+            assert_eq!(zero.checked_shr(u32::MAX).map(|cnst| cnst.get()), Some(0));
+
+            let cnst = CnstTest::new_max();
+            assert_eq!(
+                cnst.try_shl(<$Int>::BITS).map(|cnst| cnst.get()),
+                CnstTest::new(0).map(|cnst| cnst.get())
+            );
+            assert_eq!(
+                cnst.try_shr(<$Int>::BITS).map(|cnst| cnst.get()),
+                CnstTest::new(0).map(|cnst| cnst.get())
+            );
+
+            let cnst = CnstTest::new_min();
+            let expected = CnstTest::new(<$Int>::MIN >> (<$Int>::BITS - 1));
+            assert_eq!(cnst.try_shr(<$Int>::BITS), expected);
+        }
+
+        #[test]
+        fn bit_counts() {
+            let mask = MaskTest::new(0b0110).unwrap();
+            assert_eq!(mask.count_ones(), 2);
+            assert_eq!(mask.leading_zeros(), <$Int>::BITS - 3);
+            assert_eq!(mask.trailing_zeros(), 1);
+
+            let mask = MaskTest::new_max();
+            assert_eq!(mask.count_ones(), 7);
+            assert_eq!(mask.leading_zeros(), <$Int>::BITS - 7);
+            assert_eq!(mask.trailing_zeros(), 0);
+        }
+    };
+}
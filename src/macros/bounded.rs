@@ -0,0 +1,82 @@
+// Implements the `BoundedInt` trait for `Constrained` types, forwarding to their
+// inherent APIs.
+macro_rules! constrained_bounded_impl {
+    ($Int:ty, $Ty:ident, $Err:ident) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $crate::sealed::Sealed
+            for $Ty<MIN, MAX, DEF>
+        {
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $crate::BoundedInt
+            for $Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            type Int = $Int;
+            type Error = $Err<MIN, MAX>;
+
+            const MIN: $Int = MIN;
+            const MAX: $Int = MAX;
+            const DEF: $Int = DEF;
+
+            #[inline]
+            fn new(value: $Int) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+
+            #[inline]
+            fn get(&self) -> $Int {
+                self.get()
+            }
+
+            #[inline]
+            fn set(&mut self, value: $Int) -> Result<(), Self::Error> {
+                self.set(value)
+            }
+
+            #[inline]
+            fn checked_add(self, rhs: $Int) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: $Int) -> Self {
+                self.saturating_add(rhs)
+            }
+
+            #[inline]
+            fn wrapping_add(self, rhs: $Int) -> Self {
+                self.wrapping_add(rhs)
+            }
+        }
+    };
+}
+
+// Implements tests for the `BoundedInt` trait of `Constrained` types.
+#[cfg(test)]
+macro_rules! tests_bounded {
+    ($Int:ty, $ty_path:path, $Ty:ident) => {
+        use $crate::BoundedInt;
+        use $ty_path::*;
+
+        fn assert_bounded<T: BoundedInt>(one: T::Int, beyond_max: T::Int) {
+            let mut cnst = T::new(T::DEF).unwrap();
+            assert_eq!(cnst.get(), T::DEF);
+
+            cnst.set(T::MAX).unwrap();
+            assert_eq!(cnst.get(), T::MAX);
+            assert!(cnst.set(beyond_max).is_err());
+            assert!(T::new(beyond_max).is_err());
+
+            assert_eq!(cnst.checked_add(one), None);
+            assert_eq!(cnst.saturating_add(one).get(), T::MAX);
+            assert_eq!(cnst.wrapping_add(one).get(), T::MIN);
+        }
+
+        #[test]
+        fn generic_over_primitive() {
+            assert_bounded::<$Ty<3, 10, 5>>(1, 11);
+            assert_bounded::<$Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>>(1, <$Int>::MAX);
+        }
+    };
+}
@@ -60,6 +60,33 @@ macro_rules! constrained_def_impl {
             guard_arithmetics::<MIN, MAX>() && guard_default::<MIN, MAX, DEF>()
         }
 
+        // This const function is used to enforce constraints for re-ranging conversions.
+        // Relevant const generics are: `MIN`, `MAX`, `NEW_MIN` and `NEW_MAX`.
+        // The constraints are:
+        //     - `NEW_MIN` must be equal to, or lower than `MIN`.
+        //     - `NEW_MAX` must be equal to, or greater than `MAX`.
+        // This ensures that every value of the `MIN..=MAX` range is contained by the
+        // `NEW_MIN..=NEW_MAX` range.
+        #[must_use]
+        #[inline(always)]
+        #[doc(hidden)]
+        pub const fn guard_superset<
+            const MIN: $Int, const MAX: $Int, const NEW_MIN: $Int, const NEW_MAX: $Int,
+        >() -> bool {
+            NEW_MIN <= MIN && MAX <= NEW_MAX
+        }
+
+        // This const function maps a value into a sign-aware key, so values of different
+        // primitives can be compared against each other. The key holds whether the value
+        // is not negative, and the value's two's complement bits.
+        // Used to enforce constraints for conversions, see `$crate::guard_contains`.
+        #[must_use]
+        #[inline(always)]
+        #[doc(hidden)]
+        pub const fn guard_key(value: $Int) -> (bool, u128) {
+            (<$Int>::MIN == 0 || value as i128 >= 0, value as i128 as u128)
+        }
+
         #[doc = concat!("An [`", stringify!($Int), "`] value that is constrained within an inclusive range.")]
         ///
         /// The range is defined at compile time, by assigning values to the parameters
@@ -82,6 +109,34 @@ macro_rules! constrained_def_impl {
         ///
         #[doc = concat!(stringify!($Ty), " is guaranteed to have the same layout and ABI as `", stringify!($Int), "`")]
         ///
+        /// # Conversions
+        ///
+        #[doc = concat!(stringify!($Ty), " implements `From` for `Constrained` types of other primitives,")]
+        /// as long as the source range is contained by this range, and `TryFrom` for
+        /// all primitive integers. See the [crate level documentation] for examples.
+        ///
+        /// [crate level documentation]: crate#conversions
+        ///
+        /// # Operators
+        ///
+        /// Arithmetic operators from `core::ops` behave like they do for primitive
+        /// integers: if the result is out of the range's bounds, the operation panics
+        /// when `debug_assertions` are enabled, or else wraps around the range's
+        /// bounds. Note that these are the `debug_assertions` this crate is built
+        /// with, and not the `overflow-checks` of the crate using it. Division and
+        /// remainder of a signed primitive's `MIN` by `-1` always panic, like they do
+        /// for primitive integers. See [`Wrapping`], [`Saturating`], [`Checked`] and [`Strict`] for
+        /// arithmetic with explicit semantics.
+        ///
+        /// [`Wrapping`]: crate::Wrapping
+        /// [`Saturating`]: crate::Saturating
+        /// [`Checked`]: crate::Checked
+        /// [`Strict`]: crate::Strict
+        ///
+        /// `Option<Self>` implements `Sum` and `Product`, returning `None` if any
+        /// intermediate result is out of the range's bounds, or if an empty iterator's
+        /// zero or one is.
+        ///
         /// # Examples
         ///
         /// If the provided parameters satisfy the construction condition, associated
@@ -264,6 +319,35 @@ macro_rules! constrained_def_impl {
                 Self::checked_new_unguarded(value)
             }
 
+            /// Creates a new instance with provided value, wrapping around the range's
+            /// bounds if the value is out of the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `value` modulo the range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let min = Constrained::wrapping_new(", stringify!($min), ");")]
+            #[doc = concat!("assert_eq!(min.get(), ", stringify!($min), ");")]
+            ///
+            /// // Below lower bound, so it wraps around the upper bound.
+            #[doc = concat!("let max = Constrained::wrapping_new(", stringify!($l), ");")]
+            #[doc = concat!("assert_eq!(max.get(), ", stringify!($max), ");")]
+            ///
+            /// // Above upper bound, so it wraps around the lower bound.
+            #[doc = concat!("let min = Constrained::wrapping_new(", stringify!($h), ");")]
+            #[doc = concat!("assert_eq!(min.get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use]
+            pub const fn wrapping_new(value: $Int) -> Self {
+                Self::wrap(value)
+            }
+
             /// Creates a new instance with the value defined by the range's lower bound.
             ///
             /// # Example
@@ -352,9 +436,9 @@ macro_rules! constrained_def_impl {
                 debug_assert!(!(MIN > MAX), "`MIN` can't be greater than `MAX`");
 
                 if value > MAX {
-                    Err($Err::greater())
+                    Err($Err::Greater($MaxErr::with(Rejected::Value(value))))
                 } else if value < MIN {
-                    Err($Err::lower())
+                    Err($Err::Lower($MinErr::with(Rejected::Value(value))))
                 } else {
                     Ok(())
                 }
@@ -420,7 +504,698 @@ macro_rules! constrained_def_impl {
                 Ok(())
             }
 
-            /// Returns the value of the contained integer type.
+            /// Replaces the contained value, if it satifies the range's inclusive bounds,
+            /// returning the old value. If the provided value is out of bounds, it is
+            /// handed back along with an error indicating which bound was violated.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            ///
+            #[doc = concat!("let old = constrained.replace(", stringify!($max), ").unwrap();")]
+            #[doc = concat!("assert_eq!(old.get(), ", stringify!($min), ");")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            ///
+            /// // Out of inclusive bounds.
+            #[doc = concat!("let (value, err) = constrained.replace(", stringify!($h), ").unwrap_err();")]
+            #[doc = concat!("assert_eq!(value, ", stringify!($h), ");")]
+            #[doc = concat!("assert_eq!(err.value(), Some(", stringify!($h), "));")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            pub fn replace(&mut self, value: $Int) -> Result<Self, ($Int, $Err<MIN, MAX>)> {
+                match Self::in_range(value) {
+                    Ok(_) => Ok(::core::mem::replace(self, Self(value))),
+                    Err(err) => Err((value, err)),
+                }
+            }
+
+            /// Sets the contained value to the one returned by `f`, called with the
+            /// current value, if it satifies the range's inclusive bounds. If the
+            /// returned value is out of bounds, an error is returned, indicating which
+            /// bound was violated, and the contained value is left untouched.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            ///
+            /// constrained.try_set_with(|value| value + 1)?;
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), " + 1);")]
+            ///
+            /// // Out of inclusive bounds.
+            /// assert!(constrained.try_set_with(|value| value - 2).is_err());
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), " + 1);")]
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            pub fn try_set_with<F>(&mut self, f: F) -> Result<(), $Err<MIN, MAX>>
+            where
+                F: FnOnce($Int) -> $Int,
+            {
+                self.set(f(self.0))
+            }
+
+            /// Sets the contained value, saturating at the range's bounds if the
+            /// provided value is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::default();
+            ///
+            /// // Above upper bound, so it sets the upper bound value.
+            #[doc = concat!("constrained.set_saturating(", stringify!($h), ");")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            ///
+            /// // Below lower bound, so it sets the lower bound value.
+            #[doc = concat!("constrained.set_saturating(", stringify!($l), ");")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[inline]
+            pub const fn set_saturating(&mut self, value: $Int) {
+                *self = Self::saturating_new_unguarded(value);
+            }
+
+            /// Sets the contained value, wrapping around the range's bounds if the
+            /// provided value is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::default();
+            ///
+            /// // Above upper bound, so it wraps around the lower bound.
+            #[doc = concat!("constrained.set_wrapping(", stringify!($h), ");")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            ///
+            /// // Below lower bound, so it wraps around the upper bound.
+            #[doc = concat!("constrained.set_wrapping(", stringify!($l), ");")]
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[inline]
+            pub const fn set_wrapping(&mut self, value: $Int) {
+                *self = Self::wrap(value);
+            }
+
+            /// Returns the value of the contained integer type.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::default();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use]
+            #[inline(always)]
+            pub const fn get(&self) -> $Int {
+                self.0
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Converts into a type defined by the `NEW_MIN..=NEW_MAX` range, which
+            /// must contain this type's range.
+            ///
+            /// The conversion is infallible, it only compiles if `NEW_MIN` <= `MIN`
+            /// and `NEW_MAX` >= `MAX`, and if the new type can be constructed.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("let widened = constrained.widen::<", stringify!($l, $max), ">();")]
+            #[doc = concat!("assert_eq!(widened.get(), ", stringify!($max), ");")]
+            /// ```
+            ///
+            /// Conversions into a range that does not contain this type's range do not compile.
+            /// ```compile_fail
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("let widened = constrained.widen::<", stringify!($min), ", 100>();")]
+            /// ```
+            #[must_use]
+            #[inline(always)]
+            pub const fn widen<const NEW_MIN: $Int, const NEW_MAX: $Int>(self) -> $Ty<NEW_MIN, NEW_MAX>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+                $crate::Constraints<{ guard_superset::<MIN, MAX, NEW_MIN, NEW_MAX>() }>: $crate::Guard,
+            {
+                $Ty(self.0)
+            }
+
+            /// Converts into a type defined by the `NEW_MIN..=NEW_MAX` range, which
+            /// must be contained by this type's range. If the value is out of the new
+            /// range's bounds, an error is returned, indicating which bound was violated.
+            ///
+            /// Only compiles if `NEW_MIN` >= `MIN` and `NEW_MAX` <= `MAX`, and if the new
+            /// type can be constructed. See [`try_rerange`] for unrelated ranges.
+            ///
+            #[doc = concat!("[`try_rerange`]: ", stringify!($Ty), "::try_rerange")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new(50)?;
+            /// let narrowed = constrained.narrow::<10, 100>().unwrap();
+            /// assert_eq!(narrowed.get(), 50);
+            ///
+            /// // Out of the new range's inclusive bounds.
+            /// let constrained = Constrained::new_max();
+            /// assert!(constrained.narrow::<10, 100>().is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            pub const fn narrow<const NEW_MIN: $Int, const NEW_MAX: $Int>(
+                self,
+            ) -> Result<$Ty<NEW_MIN, NEW_MAX>, $Err<NEW_MIN, NEW_MAX>>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+                $crate::Constraints<{ guard_superset::<NEW_MIN, NEW_MAX, MIN, MAX>() }>: $crate::Guard,
+            {
+                $Ty::new_unguarded(self.0)
+            }
+
+            /// Converts into a type defined by the `NEW_MIN..=NEW_MAX` range. If the value
+            /// is out of the new range's bounds, an error is returned, indicating which
+            /// bound was violated.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new(50)?;
+            #[doc = concat!("let reranged = constrained.try_rerange::<10, ", stringify!($h), ">().unwrap();")]
+            /// assert_eq!(reranged.get(), 50);
+            ///
+            /// // Out of the new range's inclusive bounds.
+            /// let constrained = Constrained::new_max();
+            /// assert!(constrained.try_rerange::<10, 100>().is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            pub const fn try_rerange<const NEW_MIN: $Int, const NEW_MAX: $Int>(
+                self,
+            ) -> Result<$Ty<NEW_MIN, NEW_MAX>, $Err<NEW_MIN, NEW_MAX>>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+            {
+                $Ty::new_unguarded(self.0)
+            }
+
+            /// Converts into a type defined by the `NEW_MIN..=NEW_MAX` range. If the value
+            /// is out of the new range's bounds, the new instance is initialized with
+            /// the value of the closest bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// // Above the new range's upper bound, so it saturates at upper bound.
+            /// let constrained = Constrained::new_max();
+            /// let reranged = constrained.saturating_rerange::<10, 100>();
+            /// assert_eq!(reranged.get(), 100);
+            ///
+            /// // Below the new range's lower bound, so it saturates at lower bound.
+            /// let constrained = Constrained::new_min();
+            /// let reranged = constrained.saturating_rerange::<10, 100>();
+            /// assert_eq!(reranged.get(), 10);
+            /// ```
+            #[must_use]
+            pub const fn saturating_rerange<const NEW_MIN: $Int, const NEW_MAX: $Int>(self) -> $Ty<NEW_MIN, NEW_MAX>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+            {
+                $Ty::saturating_new_unguarded(self.0)
+            }
+
+            /// Converts into a type defined by the `NEW_MIN..=NEW_MAX` range. If the value
+            /// is out of the new range's bounds, it wraps around the new range's bounds.
+            ///
+            /// The new value is the only value contained by the new range that is
+            /// congruent to this value modulo the new range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// // Above the new range's upper bound, so it wraps around the lower bound.
+            /// let constrained = Constrained::new(101)?;
+            /// let reranged = constrained.wrapping_rerange::<10, 100>();
+            /// assert_eq!(reranged.get(), 10);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[must_use]
+            pub const fn wrapping_rerange<const NEW_MIN: $Int, const NEW_MAX: $Int>(self) -> $Ty<NEW_MIN, NEW_MAX>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+            {
+                $Ty::wrap(self.0)
+            }
+
+            /// Converts into a `Constrained` type of another primitive, or any other
+            /// type that implements `TryFrom` for this type's primitive. If the value
+            /// is out of the target range's bounds, the target's error is returned.
+            ///
+            /// This is the fallible counterpart of the `From` implementations between
+            /// `Constrained` types, which only exist if the source range is contained
+            /// by the target range.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # #![allow(incomplete_features)]
+            /// # #![feature(generic_const_exprs)]
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            /// type Target = constrained_int::i128::ConstrainedI128<-5, 50>;
+            ///
+            /// let constrained = Constrained::new(50)?;
+            /// let converted = constrained.try_convert::<Target>().unwrap();
+            /// assert_eq!(converted.get(), 50);
+            ///
+            /// // Out of the target range's inclusive bounds.
+            /// let constrained = Constrained::new_max();
+            /// assert!(constrained.try_convert::<Target>().is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[inline]
+            pub fn try_convert<T: TryFrom<$Int>>(self) -> Result<T, T::Error> {
+                T::try_from(self.0)
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Checked successor. Returns the next value, or [`None`] if this value is
+            /// the range's upper bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.checked_next().map(|c| c.get()), Some(", stringify!($min), " + 1));")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// assert_eq!(constrained.checked_next(), None);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn checked_next(self) -> Option<Self> {
+                if self.0 < MAX {
+                    Some(Self(self.0 + 1))
+                } else {
+                    None
+                }
+            }
+
+            /// Checked predecessor. Returns the previous value, or [`None`] if this
+            /// value is the range's lower bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_prev().map(|c| c.get()), Some(", stringify!($max), " - 1));")]
+            ///
+            /// let constrained = Constrained::new_min();
+            /// assert_eq!(constrained.checked_prev(), None);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn checked_prev(self) -> Option<Self> {
+                if self.0 > MIN {
+                    Some(Self(self.0 - 1))
+                } else {
+                    None
+                }
+            }
+
+            /// Saturating successor. Returns the next value, saturating at the range's
+            /// upper bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.saturating_next().get(), ", stringify!($min), " + 1);")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.saturating_next().get(), ", stringify!($max), ");")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn saturating_next(self) -> Self {
+                match self.checked_next() {
+                    Some(next) => next,
+                    None => self,
+                }
+            }
+
+            /// Saturating predecessor. Returns the previous value, saturating at the
+            /// range's lower bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.saturating_prev().get(), ", stringify!($max), " - 1);")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.saturating_prev().get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn saturating_prev(self) -> Self {
+                match self.checked_prev() {
+                    Some(prev) => prev,
+                    None => self,
+                }
+            }
+
+            /// Wrapping (cyclic) successor. Returns the next value, wrapping around to
+            /// the range's lower bound if this value is the upper bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.wrapping_next().get(), ", stringify!($min), " + 1);")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.wrapping_next().get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn wrapping_next(self) -> Self {
+                match self.checked_next() {
+                    Some(next) => next,
+                    None => Self(MIN),
+                }
+            }
+
+            /// Wrapping (cyclic) predecessor. Returns the previous value, wrapping
+            /// around to the range's upper bound if this value is the lower bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.wrapping_prev().get(), ", stringify!($max), " - 1);")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.wrapping_prev().get(), ", stringify!($max), ");")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn wrapping_prev(self) -> Self {
+                match self.checked_prev() {
+                    Some(prev) => prev,
+                    None => Self(MAX),
+                }
+            }
+
+            /// Increments the value in place, wrapping around to the range's lower
+            /// bound if the value is the upper bound. Same as
+            /// `*self = self.wrapping_next()`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// constrained.inc();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), " + 1);")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// constrained.inc();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[inline]
+            pub const fn inc(&mut self) {
+                *self = self.wrapping_next();
+            }
+
+            /// Decrements the value in place, wrapping around to the range's upper
+            /// bound if the value is the lower bound. Same as
+            /// `*self = self.wrapping_prev()`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// constrained.dec();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), " - 1);")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// constrained.dec();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[inline]
+            pub const fn dec(&mut self) {
+                *self = self.wrapping_prev();
+            }
+
+            /// Increments the value in place, or returns an error if the value is the
+            /// range's upper bound, leaving it untouched.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// assert!(constrained.checked_inc().is_ok());
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), " + 1);")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// assert!(constrained.checked_inc().is_err());
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[inline]
+            pub const fn checked_inc(&mut self) -> Result<(), $Err<MIN, MAX>> {
+                match self.checked_next() {
+                    Some(next) => {
+                        *self = next;
+                        Ok(())
+                    }
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Add(self.0, 1)))),
+                }
+            }
+
+            /// Decrements the value in place, or returns an error if the value is the
+            /// range's lower bound, leaving it untouched.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// assert!(constrained.checked_dec().is_ok());
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), " - 1);")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// assert!(constrained.checked_dec().is_err());
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[inline]
+            pub const fn checked_dec(&mut self) -> Result<(), $Err<MIN, MAX>> {
+                match self.checked_prev() {
+                    Some(prev) => {
+                        *self = prev;
+                        Ok(())
+                    }
+                    None => Err($Err::Lower($MinErr::with(Rejected::Sub(self.0, 1)))),
+                }
+            }
+
+            /// Increments the value in place, saturating at the range's upper bound.
+            /// Same as `*self = self.saturating_next()`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// constrained.saturating_inc();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[inline]
+            pub const fn saturating_inc(&mut self) {
+                *self = self.saturating_next();
+            }
+
+            /// Decrements the value in place, saturating at the range's lower bound.
+            /// Same as `*self = self.saturating_prev()`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// constrained.saturating_dec();
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[inline]
+            pub const fn saturating_dec(&mut self) {
+                *self = self.saturating_prev();
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Restricts the value to the `lo..=hi` interval, returning `lo` if the
+            /// value is lower than `lo`, or `hi` if it is greater than `hi`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo` is greater than `hi`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let lo = Constrained::new(", stringify!($min), " + 1)?;")]
+            #[doc = concat!("let hi = Constrained::new(", stringify!($max), " - 1)?;")]
+            ///
+            /// assert_eq!(Constrained::new_min().clamp_to(lo, hi), lo);
+            /// assert_eq!(Constrained::new_max().clamp_to(lo, hi), hi);
+            /// assert_eq!(lo.clamp_to(lo, hi), lo);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn clamp_to(self, lo: Self, hi: Self) -> Self {
+                assert!(lo.0 <= hi.0, "`lo` must not be greater than `hi`");
+
+                if self.0 < lo.0 {
+                    lo
+                } else if self.0 > hi.0 {
+                    hi
+                } else {
+                    self
+                }
+            }
+
+            /// Calculates the middle point of `self` and `rhs`, as if `(self + rhs) / 2`
+            /// was computed in a wider integer type. The result is rounded towards zero,
+            /// like the primitive's `midpoint`.
+            ///
+            /// The result is always contained by the range, and it is computed
+            /// without overflowing the primitive.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let lhs = Constrained::new(10)?;
+            /// let rhs = Constrained::new(15)?;
+            ///
+            /// assert_eq!(lhs.midpoint(rhs).get(), 12);
+            /// assert_eq!(rhs.midpoint(rhs), rhs);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn midpoint(self, rhs: Self) -> Self {
+                Self(Self::half_sum(self.0, rhs.0))
+            }
+
+            /// Linearly remaps the value onto the `NEW_MIN..=NEW_MAX` range, rounded
+            /// down, so that `MIN` maps to `NEW_MIN` and `MAX` maps to `NEW_MAX`.
+            ///
+            /// The intermediate arithmetics are computed without overflowing, for any
+            /// pair of ranges. If the new range fits in another primitive, the result
+            /// can then be converted with `From` or `into`.
             ///
             /// # Example
             ///
@@ -429,13 +1204,42 @@ macro_rules! constrained_def_impl {
             ///
             #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
             ///
-            /// let constrained = Constrained::default();
-            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// let min = Constrained::new_min();
+            /// let max = Constrained::new_max();
+            ///
+            /// assert_eq!(min.scale_to::<0, 100>().get(), 0);
+            /// assert_eq!(max.scale_to::<0, 100>().get(), 100);
+            /// // The range has no exact middle point, so this is off by one at most.
+            /// assert!(min.midpoint(max).scale_to::<0, 100>().get().abs_diff(50) <= 1);
             /// ```
-            #[must_use]
-            #[inline(always)]
-            pub const fn get(&self) -> $Int {
-                self.0
+            ///
+            /// Remapping a 12 bit reading onto a percentage.
+            /// ```
+            /// use constrained_int::u8::ConstrainedU8;
+            /// use constrained_int::u16::ConstrainedU16;
+            ///
+            /// type Reading = ConstrainedU16<0, 4095>;
+            /// type Percent = ConstrainedU8<0, 100>;
+            ///
+            /// let reading = Reading::new(2048)?;
+            /// let percent: Percent = reading.scale_to::<0, 100>().into();
+            /// assert_eq!(percent.get(), 50);
+            /// # Ok::<(), constrained_int::u16::ConstrainedU16Error<0, 4095>>(())
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn scale_to<const NEW_MIN: $Int, const NEW_MAX: $Int>(self) -> $Ty<NEW_MIN, NEW_MAX>
+            where
+                $crate::Constraints<{ guard_construction::<NEW_MIN, NEW_MAX, NEW_MIN>() }>: $crate::Guard,
+            {
+                let offset = self.0.abs_diff(MIN) as u128;
+                let span = MAX.abs_diff(MIN) as u128;
+                let new_span = NEW_MAX.abs_diff(NEW_MIN) as u128;
+
+                // The offset is never greater than the span, so the scaled offset
+                // is contained by the new span, and the truncating cast is lossless
+                // modulo the primitive's size.
+                let scaled = $crate::scale::mul_div(offset, new_span, span);
+                $Ty(NEW_MIN.wrapping_add(scaled as $Int))
             }
         }
 
@@ -464,6 +1268,75 @@ macro_rules! constrained_def_impl {
         // Implements some ::core::fmt traits for `Constrained` types.
         constrained_fmt_impl! { Debug, Display, Binary, Octal, LowerHex, UpperHex for $Ty($Int) }
 
+        // What is known about the value that violated a range bound.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        enum Rejected {
+            // The value is not known, or not representable by the primitive.
+            Unknown,
+            Value($Int),
+            // A value out of the primitive's bounds, as keyed by `guard_key`.
+            Wide((bool, u128)),
+            // Operands of the fallible arithmetic APIs.
+            Add($Int, $Int),
+            Sub($Int, $Int),
+            Mul($Int, $Int),
+            Div($Int, $Int),
+            Pow($Int, u32),
+            // Only constructed by signed types, the unsigned operands of mixed
+            // signedness APIs are kept if not representable by the primitive.
+            #[allow(dead_code)]
+            Neg($Int),
+            #[allow(dead_code)]
+            AddUnsigned($Int, u128),
+            #[allow(dead_code)]
+            SubUnsigned($Int, u128),
+        }
+
+        impl Rejected {
+            #[must_use]
+            const fn value(&self) -> Option<$Int> {
+                match *self {
+                    Self::Unknown | Self::Wide(_) => None,
+                    Self::AddUnsigned(..) | Self::SubUnsigned(..) => None,
+                    Self::Value(value) => Some(value),
+                    Self::Add(lhs, rhs) => lhs.checked_add(rhs),
+                    Self::Sub(lhs, rhs) => lhs.checked_sub(rhs),
+                    Self::Mul(lhs, rhs) => lhs.checked_mul(rhs),
+                    Self::Div(lhs, rhs) => lhs.checked_div(rhs),
+                    Self::Pow(base, exp) => base.checked_pow(exp),
+                    Self::Neg(value) => value.checked_neg(),
+                }
+            }
+
+            #[must_use]
+            const fn operands(&self) -> Option<($Int, $Int)> {
+                match *self {
+                    Self::Add(lhs, rhs) | Self::Sub(lhs, rhs) => Some((lhs, rhs)),
+                    Self::Mul(lhs, rhs) | Self::Div(lhs, rhs) => Some((lhs, rhs)),
+                    _ => None,
+                }
+            }
+
+            // Writes what is known about the value, if anything, as a suffix of the
+            // bound errors' messages.
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::Unknown => Ok(()),
+                    Self::Value(value) => write!(f, ", found {value}"),
+                    Self::Wide((true, bits)) => write!(f, ", found {bits}"),
+                    Self::Wide((false, bits)) => write!(f, ", found {}", *bits as i128),
+                    Self::Add(lhs, rhs) => write!(f, ", found {lhs} + {rhs}"),
+                    Self::Sub(lhs, rhs) => write!(f, ", found {lhs} - {rhs}"),
+                    Self::Mul(lhs, rhs) => write!(f, ", found {lhs} * {rhs}"),
+                    Self::Div(lhs, rhs) => write!(f, ", found {lhs} / {rhs}"),
+                    Self::Pow(base, exp) => write!(f, ", found {base}.pow({exp})"),
+                    Self::Neg(value) => write!(f, ", found -({value})"),
+                    Self::AddUnsigned(lhs, rhs) => write!(f, ", found {lhs} + {rhs}"),
+                    Self::SubUnsigned(lhs, rhs) => write!(f, ", found {lhs} - {rhs}"),
+                }
+            }
+        }
+
         #[doc = concat!("This error indicates that a [`", stringify!($Int), "`] value ")]
         /// violates the range's lower bound.
         ///
@@ -484,7 +1357,7 @@ macro_rules! constrained_def_impl {
         /// }
         /// ```
         #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        pub struct $MinErr<const MIN: $Int>(());
+        pub struct $MinErr<const MIN: $Int>(Rejected);
 
         impl<const MIN: $Int> $MinErr<MIN> {
             /// The minimum **inclusive** bound enforced by the range.
@@ -493,13 +1366,67 @@ macro_rules! constrained_def_impl {
             #[must_use]
             #[inline(always)]
             const fn new() -> Self {
-                Self(())
+                Self(Rejected::Unknown)
+            }
+
+            #[must_use]
+            #[inline(always)]
+            const fn with(rejected: Rejected) -> Self {
+                Self(rejected)
+            }
+
+            /// Returns the value that violated the bound, if it is known and it
+            #[doc = concat!("is representable by [`", stringify!($Int), "`].")]
+            ///
+            /// The value is known for errors returned by constructors, setters,
+            /// conversions and fallible operations, as long as it does not overflow the
+            /// primitive. Parsing stops at the first digit that overflows it, so those
+            /// errors never know the value.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($Err) , "};")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("match Constrained::new(", stringify!($l), ") {")]
+            #[doc = concat!("    Err(", stringify!($Err), "::Lower(err)) => assert_eq!(err.value(), Some(", stringify!($l), ")),")]
+            ///     _ => unreachable!(),
+            /// }
+            /// ```
+            #[must_use]
+            pub const fn value(&self) -> Option<$Int> {
+                self.0.value()
+            }
+
+            /// Returns the `(lhs, rhs)` operands of the failed addition, subtraction,
+            /// multiplication or division that returned this error, if any and if they
+            #[doc = concat!("are representable by [`", stringify!($Int), "`].")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($Err) , "};")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            /// match constrained.try_sub(2) {
+            #[doc = concat!("    Err(err) => assert_eq!(err.operands(), Some((", stringify!($min), ", 2))),")]
+            ///     _ => unreachable!(),
+            /// }
+            /// ```
+            #[must_use]
+            pub const fn operands(&self) -> Option<($Int, $Int)> {
+                self.0.operands()
             }
         }
 
         impl<const MIN: $Int> ::core::fmt::Display for $MinErr<MIN> {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                write!(f, "value must be greater or equal to {MIN}")
+                write!(f, "value must be greater or equal to {MIN}")?;
+                self.0.fmt(f)
             }
         }
 
@@ -526,7 +1453,7 @@ macro_rules! constrained_def_impl {
         /// }
         /// ```
         #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        pub struct $MaxErr<const MAX: $Int>(());
+        pub struct $MaxErr<const MAX: $Int>(Rejected);
 
         impl<const MAX: $Int> $MaxErr<MAX> {
             /// The maximum **inclusive** bound enforced by the range.
@@ -535,13 +1462,67 @@ macro_rules! constrained_def_impl {
             #[must_use]
             #[inline(always)]
             const fn new() -> Self {
-                Self(())
+                Self(Rejected::Unknown)
+            }
+
+            #[must_use]
+            #[inline(always)]
+            const fn with(rejected: Rejected) -> Self {
+                Self(rejected)
+            }
+
+            /// Returns the value that violated the bound, if it is known and it
+            #[doc = concat!("is representable by [`", stringify!($Int), "`].")]
+            ///
+            /// The value is known for errors returned by constructors, setters,
+            /// conversions and fallible operations, as long as it does not overflow the
+            /// primitive. Parsing stops at the first digit that overflows it, so those
+            /// errors never know the value.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($Err) , "};")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("match Constrained::new(", stringify!($h), ") {")]
+            #[doc = concat!("    Err(", stringify!($Err), "::Greater(err)) => assert_eq!(err.value(), Some(", stringify!($h), ")),")]
+            ///     _ => unreachable!(),
+            /// }
+            /// ```
+            #[must_use]
+            pub const fn value(&self) -> Option<$Int> {
+                self.0.value()
+            }
+
+            /// Returns the `(lhs, rhs)` operands of the failed addition, subtraction,
+            /// multiplication or division that returned this error, if any and if they
+            #[doc = concat!("are representable by [`", stringify!($Int), "`].")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($Err) , "};")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// match constrained.try_add(2) {
+            #[doc = concat!("    Err(err) => assert_eq!(err.operands(), Some((", stringify!($max), ", 2))),")]
+            ///     _ => unreachable!(),
+            /// }
+            /// ```
+            #[must_use]
+            pub const fn operands(&self) -> Option<($Int, $Int)> {
+                self.0.operands()
             }
         }
 
         impl<const MAX: $Int> ::core::fmt::Display for $MaxErr<MAX> {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                write!(f, "value must be lower or equal to {MAX}")
+                write!(f, "value must be lower or equal to {MAX}")?;
+                self.0.fmt(f)
             }
         }
 
@@ -652,19 +1633,74 @@ macro_rules! constrained_def_impl {
         }
 
         impl<const MIN: $Int, const MAX: $Int> $Err<MIN, MAX> {
+            /// Returns the value that violated the range's bound, if it is known and
+            #[doc = concat!("it is representable by [`", stringify!($Int), "`].")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            #[doc = concat!("let err = Constrained::new(", stringify!($h), ").unwrap_err();")]
+            #[doc = concat!("assert_eq!(err.value(), Some(", stringify!($h), "));")]
+            /// ```
+            #[must_use]
+            pub const fn value(&self) -> Option<$Int> {
+                match self {
+                    Self::Lower(err) => err.value(),
+                    Self::Greater(err) => err.value(),
+                }
+            }
+
+            /// Returns the `(lhs, rhs)` operands of the failed addition, subtraction,
+            /// multiplication or division that returned this error, if any and if they
+            #[doc = concat!("are representable by [`", stringify!($Int), "`].")]
+            #[must_use]
+            pub const fn operands(&self) -> Option<($Int, $Int)> {
+                match self {
+                    Self::Lower(err) => err.operands(),
+                    Self::Greater(err) => err.operands(),
+                }
+            }
+
             /// Returns `Lower` variant.
             #[must_use]
             #[inline(always)]
-            const fn lower() -> Self {
+            pub(crate) const fn lower() -> Self {
                 Self::Lower($MinErr::<MIN>::new())
             }
 
             /// Returns `Greater` variant.
             #[must_use]
             #[inline(always)]
-            const fn greater() -> Self {
+            pub(crate) const fn greater() -> Self {
                 Self::Greater($MaxErr::<MAX>::new())
             }
+
+            /// Returns `Lower` variant, with the rejected value.
+            #[must_use]
+            pub(crate) const fn lower_value(value: $Int) -> Self {
+                Self::Lower($MinErr::<MIN>::with(Rejected::Value(value)))
+            }
+
+            /// Returns `Greater` variant, with the rejected value.
+            #[must_use]
+            pub(crate) const fn greater_value(value: $Int) -> Self {
+                Self::Greater($MaxErr::<MAX>::with(Rejected::Value(value)))
+            }
+
+            /// Returns the variant violated by a value out of the primitive's bounds,
+            /// given its `guard_key`.
+            #[must_use]
+            pub(crate) const fn wide(key: (bool, u128)) -> Self {
+                if key.0 {
+                    Self::Greater($MaxErr::<MAX>::with(Rejected::Wide(key)))
+                } else {
+                    Self::Lower($MinErr::<MIN>::with(Rejected::Wide(key)))
+                }
+            }
         }
 
         impl<const MIN: $Int, const MAX: $Int> From<$MinErr<MIN>> for $Err<MIN, MAX> {
@@ -730,10 +1766,10 @@ macro_rules! tests_common {
         #[test]
         fn new_unbounded() {
             let err = CnstTest::new(LOWER_MIN).expect_err("expected value lower than `MIN`");
-            assert_eq!(err, $Err::lower());
+            assert_eq!(err, $Err::lower_value(LOWER_MIN));
 
             let err = CnstTest::new(GREATER_MAX).expect_err("expected value greater than `MAX`");
-            assert_eq!(err, $Err::greater());
+            assert_eq!(err, $Err::greater_value(GREATER_MAX));
         }
 
         #[test]
@@ -776,6 +1812,28 @@ macro_rules! tests_common {
             assert_eq!(CnstTest::checked_new(GREATER_MAX), None);
         }
 
+        #[test]
+        fn wrapping_new_bounded() {
+            let mut constrained: CnstTest;
+
+            constrained = CnstTest::wrapping_new(CnstTest::MIN);
+            assert_eq!(constrained.get(), CnstTest::MIN);
+
+            constrained = CnstTest::wrapping_new(CnstTest::MAX);
+            assert_eq!(constrained.get(), CnstTest::MAX);
+        }
+
+        #[test]
+        fn wrapping_new_unbounded() {
+            let mut constrained: CnstTest;
+
+            constrained = CnstTest::wrapping_new(LOWER_MIN);
+            assert_eq!(constrained.get(), CnstTest::MAX);
+
+            constrained = CnstTest::wrapping_new(GREATER_MAX);
+            assert_eq!(constrained.get(), CnstTest::MIN);
+        }
+
         #[test]
         fn constrained_set_bounded() {
             let mut constrained = CnstTest::default();
@@ -792,10 +1850,55 @@ macro_rules! tests_common {
             let mut constrained = CnstTest::default();
 
             let err = constrained.set(LOWER_MIN).expect_err("expected value lower than `MIN`");
-            assert_eq!(err, $Err::lower());
+            assert_eq!(err, $Err::lower_value(LOWER_MIN));
 
             let err = constrained.set(GREATER_MAX).expect_err("expected value greater than `MAX`");
-            assert_eq!(err, $Err::greater())
+            assert_eq!(err, $Err::greater_value(GREATER_MAX))
+        }
+
+        #[test]
+        fn constrained_replace() {
+            let mut constrained = CnstTest::new_min();
+
+            let old = constrained.replace(CnstTest::MAX).expect("in range value");
+            assert_eq!(old, CnstTest::new_min());
+            assert_eq!(constrained, CnstTest::new_max());
+
+            let rejected =
+                constrained.replace(LOWER_MIN).expect_err("expected value lower than `MIN`");
+            assert_eq!(rejected, (LOWER_MIN, $Err::lower_value(LOWER_MIN)));
+
+            let rejected =
+                constrained.replace(GREATER_MAX).expect_err("expected value greater than `MAX`");
+            assert_eq!(rejected, (GREATER_MAX, $Err::greater_value(GREATER_MAX)));
+            assert_eq!(constrained, CnstTest::new_max());
+        }
+
+        #[test]
+        fn constrained_try_set_with() {
+            let mut constrained = CnstTest::new_min();
+
+            constrained.try_set_with(|value| value + 1).expect("in range value");
+            assert_eq!(constrained.get(), CnstTest::MIN + 1);
+
+            let err = constrained
+                .try_set_with(|_| GREATER_MAX)
+                .expect_err("expected value greater than `MAX`");
+            assert_eq!(err, $Err::greater_value(GREATER_MAX));
+            assert_eq!(constrained.get(), CnstTest::MIN + 1);
+        }
+
+        #[test]
+        fn constrained_set_saturating_and_wrapping() {
+            let mut constrained = CnstTest::default();
+
+            for value in [LOWER_MIN, CnstTest::MIN, CnstTest::MAX, GREATER_MAX] {
+                constrained.set_saturating(value);
+                assert_eq!(constrained, CnstTest::saturating_new(value));
+
+                constrained.set_wrapping(value);
+                assert_eq!(constrained, CnstTest::wrapping_new(value));
+            }
         }
 
         #[test]
@@ -849,11 +1952,226 @@ macro_rules! tests_common {
         #[test]
         fn constrained_try_from_unbounded() {
             let err = CnstTest::try_from(LOWER_MIN).expect_err("expected value lower than `MIN`");
-            assert_eq!(err, $Err::lower());
+            assert_eq!(err, $Err::lower_value(LOWER_MIN));
 
             let err =
                 CnstTest::try_from(GREATER_MAX).expect_err("expected value greater than `MAX`");
-            assert_eq!(err, $Err::greater());
+            assert_eq!(err, $Err::greater_value(GREATER_MAX));
+        }
+
+        #[test]
+        fn widen() {
+            type WideTest = $Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>;
+
+            let wide: WideTest = CnstTest::new_min().widen();
+            assert_eq!(wide.get(), CnstTest::MIN);
+
+            let wide: WideTest = CnstTest::new_max().widen();
+            assert_eq!(wide.get(), CnstTest::MAX);
+
+            let same: CnstTest = CnstTest::new_max().widen();
+            assert_eq!(same.get(), CnstTest::MAX);
+        }
+
+        #[test]
+        fn narrow() {
+            let narrow = CnstTest::new(10).unwrap().narrow::<10, 20>().unwrap();
+            assert_eq!(narrow.get(), 10);
+
+            let narrow = CnstTest::new(20).unwrap().narrow::<10, 20>().unwrap();
+            assert_eq!(narrow.get(), 20);
+
+            let err = CnstTest::new(9).unwrap().narrow::<10, 20>().unwrap_err();
+            assert_eq!(err, $Err::lower_value(9));
+
+            let err = CnstTest::new(21).unwrap().narrow::<10, 20>().unwrap_err();
+            assert_eq!(err, $Err::greater_value(21));
+        }
+
+        #[test]
+        fn try_rerange() {
+            type OtherTest = $Ty<{ <$Int>::MIN }, 20>;
+
+            let other: OtherTest = CnstTest::new(20).unwrap().try_rerange().unwrap();
+            assert_eq!(other.get(), 20);
+
+            let err = CnstTest::new(21).unwrap().try_rerange::<{ <$Int>::MIN }, 20>().unwrap_err();
+            assert_eq!(err, $Err::greater_value(21));
+
+            let err = CnstTest::new_min().try_rerange::<10, { <$Int>::MAX }>().unwrap_err();
+            assert_eq!(err, $Err::lower_value(CnstTest::MIN));
+        }
+
+        #[test]
+        fn saturating_rerange() {
+            let cnst = CnstTest::new(15).unwrap().saturating_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 15);
+
+            let cnst = CnstTest::new_min().saturating_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 10);
+
+            let cnst = CnstTest::new_max().saturating_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 20);
+        }
+
+        #[test]
+        fn wrapping_rerange() {
+            let cnst = CnstTest::new(15).unwrap().wrapping_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 15);
+
+            let cnst = CnstTest::new(9).unwrap().wrapping_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 20);
+
+            let cnst = CnstTest::new(21).unwrap().wrapping_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 10);
+
+            // Range size is 11, so 42 is congruent to 20 and 43 to 10.
+            let cnst = CnstTest::new(42).unwrap().wrapping_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 20);
+
+            let cnst = CnstTest::new(43).unwrap().wrapping_rerange::<10, 20>();
+            assert_eq!(cnst.get(), 10);
+        }
+
+        #[test]
+        fn checked_next_and_prev() {
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+
+            assert_eq!(min.checked_next().map(|c| c.get()), Some(CnstTest::MIN + 1));
+            assert_eq!(max.checked_prev().map(|c| c.get()), Some(CnstTest::MAX - 1));
+            assert_eq!(max.checked_next(), None);
+            assert_eq!(min.checked_prev(), None);
+        }
+
+        #[test]
+        fn saturating_next_and_prev() {
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+
+            assert_eq!(min.saturating_next().get(), CnstTest::MIN + 1);
+            assert_eq!(max.saturating_prev().get(), CnstTest::MAX - 1);
+            assert_eq!(max.saturating_next(), max);
+            assert_eq!(min.saturating_prev(), min);
+        }
+
+        #[test]
+        fn wrapping_next_and_prev() {
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+
+            assert_eq!(min.wrapping_next().get(), CnstTest::MIN + 1);
+            assert_eq!(max.wrapping_prev().get(), CnstTest::MAX - 1);
+            assert_eq!(max.wrapping_next(), min);
+            assert_eq!(min.wrapping_prev(), max);
+        }
+
+        #[test]
+        fn clamp_to() {
+            let lo = CnstTest::new(CnstTest::MIN + 1).unwrap();
+            let hi = CnstTest::new(CnstTest::MAX - 1).unwrap();
+
+            assert_eq!(CnstTest::new_min().clamp_to(lo, hi), lo);
+            assert_eq!(CnstTest::new_max().clamp_to(lo, hi), hi);
+            assert_eq!(lo.clamp_to(lo, hi), lo);
+            assert_eq!(hi.clamp_to(lo, lo), lo);
+        }
+
+        #[test]
+        #[should_panic]
+        fn clamp_to_inverted_bounds() {
+            let _ = CnstTest::default().clamp_to(CnstTest::new_max(), CnstTest::new_min());
+        }
+
+        #[test]
+        fn midpoint() {
+            type SmallTest = $Ty<2, 9>;
+
+            for lhs in SmallTest::range() {
+                for rhs in SmallTest::range() {
+                    let expected = (lhs + rhs) / 2;
+                    let midpoint =
+                        SmallTest::new(lhs).unwrap().midpoint(SmallTest::new(rhs).unwrap());
+                    assert_eq!(midpoint.get(), expected);
+                }
+            }
+
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+            assert_eq!(max.midpoint(max), max);
+            assert_eq!(min.midpoint(min), min);
+            assert!(CnstTest::range().contains(&min.midpoint(max).get()));
+        }
+
+        #[test]
+        fn scale_to() {
+            type SmallTest = $Ty<2, 10>;
+
+            for value in SmallTest::range() {
+                let scaled = SmallTest::new(value).unwrap().scale_to::<20, 36>();
+                assert_eq!(scaled.get(), 20 + (value - 2) * 2);
+
+                let scaled = SmallTest::new(value).unwrap().scale_to::<0, 3>();
+                assert_eq!(scaled.get(), (value - 2) * 3 / 8);
+            }
+
+            type WideTest = $Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>;
+            assert_eq!(WideTest::new_max().scale_to::<0, 100>().get(), 100);
+            assert_eq!(WideTest::new_min().scale_to::<0, 100>().get(), 0);
+            assert_eq!(
+                CnstTest::new_min().scale_to::<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>().get(),
+                <$Int>::MIN
+            );
+            assert_eq!(
+                CnstTest::new_max().scale_to::<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>().get(),
+                <$Int>::MAX - 1
+            );
+            assert_eq!(CnstTest::new_max().scale_to::<2, 10>().get(), 10);
+        }
+
+        #[test]
+        fn inc_and_dec_cycle() {
+            type Phase = $Ty<0, 7>;
+            let mut phase = Phase::new_min();
+
+            for value in (1..=7).chain(0..=1) {
+                phase.inc();
+                assert_eq!(phase.get(), value);
+            }
+
+            for value in (0..=0).chain((0..=7).rev()) {
+                phase.dec();
+                assert_eq!(phase.get(), value);
+            }
+        }
+
+        #[test]
+        fn checked_and_saturating_inc_and_dec() {
+            type Phase = $Ty<0, 7>;
+            let mut phase = Phase::new_min();
+
+            for value in 1..=7 {
+                assert_eq!(phase.checked_inc(), Ok(()));
+                assert_eq!(phase.get(), value);
+            }
+            let greater = $Err::Greater($MaxErr::with(Rejected::Add(7, 1)));
+            assert_eq!(phase.checked_inc(), Err(greater));
+            phase.saturating_inc();
+            assert_eq!(phase, Phase::new_max());
+
+            for value in (0..=6).rev() {
+                assert_eq!(phase.checked_dec(), Ok(()));
+                assert_eq!(phase.get(), value);
+            }
+            let lower = $Err::Lower($MinErr::with(Rejected::Sub(0, 1)));
+            assert_eq!(phase.checked_dec(), Err(lower));
+            phase.saturating_dec();
+            assert_eq!(phase, Phase::new_min());
+
+            phase.saturating_inc();
+            assert_eq!(phase.get(), 1);
+            phase.saturating_dec();
+            assert_eq!(phase.get(), 0);
         }
 
         #[cfg(feature = "std")]
@@ -896,11 +2214,11 @@ macro_rules! tests_common {
             type TestMinErr = $MinErr<{ <$Int>::MIN }>;
             type TestMaxErr = $MaxErr<{ <$Int>::MAX - 1 }>;
 
-            let min_err: TestMinErr = $MinErr(());
+            let min_err: TestMinErr = $MinErr::new();
             let err: TestError = $Err::from(min_err);
             assert_eq!(err, $Err::Lower(min_err));
 
-            let max_err: TestMaxErr = $MaxErr(());
+            let max_err: TestMaxErr = $MaxErr::new();
             let err: TestError = $Err::from(max_err);
             assert_eq!(err, $Err::Greater(max_err));
         }
@@ -912,11 +2230,11 @@ macro_rules! tests_common {
             type TestMinErr = $MinErr<{ <$Int>::MIN }>;
             type TestMaxErr = $MaxErr<{ <$Int>::MAX - 1 }>;
 
-            let min_err: TestMinErr = $MinErr(());
+            let min_err: TestMinErr = $MinErr::new();
             let err: TestError = $Err::lower();
             assert_eq!(err.to_string(), min_err.to_string());
 
-            let max_err: TestMaxErr = $MaxErr(());
+            let max_err: TestMaxErr = $MaxErr::new();
             let err: TestError = $Err::greater();
             assert_eq!(err.to_string(), max_err.to_string());
         }
@@ -926,7 +2244,7 @@ macro_rules! tests_common {
         fn min_err_display_impl() {
             type TestMin = $MinErr<{ <$Int>::MIN }>;
 
-            let min_err: TestMin = $MinErr(());
+            let min_err: TestMin = $MinErr::new();
             assert_eq!(
                 min_err.to_string(),
                 format!("value must be greater or equal to {}", TestMin::MIN)
@@ -938,11 +2256,146 @@ macro_rules! tests_common {
         fn max_err_display_impl() {
             type TestMax = $MaxErr<{ <$Int>::MAX }>;
 
-            let max_err: TestMax = $MaxErr(());
+            let max_err: TestMax = $MaxErr::new();
             assert_eq!(
                 max_err.to_string(),
                 format!("value must be lower or equal to {}", TestMax::MAX)
             );
         }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn rejected_display() {
+            type TestMin = $MinErr<{ <$Int>::MIN + 1 }>;
+
+            let err = TestMin::with(Rejected::Value(<$Int>::MIN));
+            let expected = format!(
+                "value must be greater or equal to {}, found {}",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Add(<$Int>::MIN, 0));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} + 0",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Sub(<$Int>::MIN, 1));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} - 1",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Mul(<$Int>::MIN, 2));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} * 2",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Div(<$Int>::MIN, 1));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} / 1",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Pow(<$Int>::MIN, 3));
+            let expected = format!(
+                "value must be greater or equal to {}, found {}.pow(3)",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Neg(<$Int>::MIN));
+            let expected = format!(
+                "value must be greater or equal to {}, found -({})",
+                TestMin::MIN,
+                <$Int>::MIN
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Wide((false, i128::MIN as u128)));
+            let expected =
+                format!("value must be greater or equal to {}, found {}", TestMin::MIN, i128::MIN);
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::Wide((true, u128::MAX)));
+            let expected =
+                format!("value must be greater or equal to {}, found {}", TestMin::MIN, u128::MAX);
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::AddUnsigned(<$Int>::MIN, u128::MAX));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} + {}",
+                TestMin::MIN,
+                <$Int>::MIN,
+                u128::MAX
+            );
+            assert_eq!(err.to_string(), expected);
+
+            let err = TestMin::with(Rejected::SubUnsigned(<$Int>::MIN, u128::MAX));
+            let expected = format!(
+                "value must be greater or equal to {}, found {} - {}",
+                TestMin::MIN,
+                <$Int>::MIN,
+                u128::MAX
+            );
+            assert_eq!(err.to_string(), expected);
+        }
+
+        #[test]
+        fn rejected_value() {
+            type TestError = $Err<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+            let err = CnstTest::new(LOWER_MIN).unwrap_err();
+            assert_eq!(err.value(), Some(LOWER_MIN));
+            assert_eq!(err.operands(), None);
+
+            let mut constrained = CnstTest::default();
+            let err = constrained.set(GREATER_MAX).unwrap_err();
+            assert_eq!(err.value(), Some(GREATER_MAX));
+
+            let err: TestError = $Err::lower();
+            assert_eq!(err.value(), None);
+            assert_eq!(err.operands(), None);
+        }
+
+        #[test]
+        fn rejected_operands() {
+            let max = CnstTest::new_max();
+
+            let err = max.try_add(1).unwrap_err();
+            assert_eq!(err.operands(), Some((CnstTest::MAX, 1)));
+            assert_eq!(err.value(), Some(GREATER_MAX));
+
+            // The result is not representable by the primitive.
+            let err = max.try_add(2).unwrap_err();
+            assert_eq!(err.operands(), Some((CnstTest::MAX, 2)));
+            assert_eq!(err.value(), None);
+
+            let err = CnstTest::new_min().try_sub(1).unwrap_err();
+            assert_eq!(err.operands(), Some((CnstTest::MIN, 1)));
+            assert_eq!(err.value(), Some(LOWER_MIN));
+
+            let err = max.try_mul(2).unwrap_err();
+            assert_eq!(err.operands(), Some((CnstTest::MAX, 2)));
+            assert_eq!(err.value(), None);
+
+            // The exponent is not an operand of the primitive's type.
+            let err = max.try_pow(2).unwrap_err();
+            assert_eq!(err, $Err::Greater($MaxErr::with(Rejected::Pow(CnstTest::MAX, 2))));
+            assert_eq!(err.operands(), None);
+            assert_eq!(err.value(), None);
+        }
     };
 }
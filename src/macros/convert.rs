@@ -0,0 +1,75 @@
+// Implements conversions between `Constrained` types and primitives of
+// different widths and signedness, for every pair of the provided types.
+//
+// A `TryFrom` implementation between `Constrained` types is not possible, it would
+// conflict with core's blanket `impl<T, U: Into<T>> TryFrom<U> for T` since the
+// compiler can't prove that a guarded `From` implementation does not apply. The
+// fallible path goes through the source primitive instead, see `try_convert`.
+macro_rules! constrained_conversion_impl {
+    ($head:tt $(, $tail:tt)* $(,)?) => {
+        $(
+            constrained_from_impl! { $head, $tail }
+            constrained_from_impl! { $tail, $head }
+        )*
+
+        constrained_conversion_impl! { $($tail),* }
+    };
+    () => {};
+}
+
+// Implements conversions from a source `Constrained` type and primitive into a
+// target `Constrained` type.
+macro_rules! constrained_from_impl {
+    (   { $SInt:ty, $smd:ident, $STy:ident, $SErr:ident },
+        { $Int:ty, $md:ident, $Ty:ident, $Err:ident }
+    ) => {
+        // Guard this constructor and the source range containment.
+        impl<
+                const SMIN: $SInt,
+                const SMAX: $SInt,
+                const SDEF: $SInt,
+                const MIN: $Int,
+                const MAX: $Int,
+                const DEF: $Int,
+            > From<$crate::$smd::$STy<SMIN, SMAX, SDEF>> for $crate::$md::$Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>:
+                $crate::Guard,
+            $crate::Constraints<
+                {
+                    $crate::guard_contains(
+                        $crate::$smd::guard_key(SMIN),
+                        $crate::$smd::guard_key(SMAX),
+                        $crate::$md::guard_key(MIN),
+                        $crate::$md::guard_key(MAX),
+                    )
+                },
+            >: $crate::Guard,
+        {
+            #[inline]
+            fn from(value: $crate::$smd::$STy<SMIN, SMAX, SDEF>) -> Self {
+                // Source range is contained by this range, the cast is lossless
+                // and the value is never saturated.
+                Self::saturating_new(value.get() as $Int)
+            }
+        }
+
+        // Guard this constructor.
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> TryFrom<$SInt>
+            for $crate::$md::$Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>:
+                $crate::Guard,
+        {
+            type Error = $crate::$md::$Err<MIN, MAX>;
+
+            fn try_from(value: $SInt) -> Result<Self, Self::Error> {
+                match <$Int>::try_from(value) {
+                    Ok(value) => Self::new(value),
+                    // Out of the primitive's bounds, so also out of the range's bounds.
+                    Err(_) => Err($crate::$md::$Err::wide($crate::$smd::guard_key(value))),
+                }
+            }
+        }
+    };
+}
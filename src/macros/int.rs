@@ -224,8 +224,8 @@ macro_rules! constrained_int_impl {
             pub const fn try_add(self, rhs: $SigInt) -> Result<Self, $Err<MIN, MAX>> {
                 match self.checked_add(rhs) {
                     Some(this) => Ok(this),
-                    None if rhs.is_positive() => Err($Err::greater()),
-                    None => Err($Err::lower()),
+                    None if rhs.is_positive() => Err($Err::Greater($MaxErr::with(Rejected::Add(self.0, rhs)))),
+                    None => Err($Err::Lower($MinErr::with(Rejected::Add(self.0, rhs)))),
                 }
             }
 
@@ -245,9 +245,10 @@ macro_rules! constrained_int_impl {
             /// ```
             #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn try_add_unsigned(self, rhs: $UnsInt) -> Result<Self, $MaxErr<MAX>> {
-                match self.checked_add_unsigned(rhs) {
-                    Some(this) => Ok(this),
-                    None => Err($MaxErr::new()),
+                match self.0.checked_add_unsigned(rhs) {
+                    Some(value) if value <= MAX => Ok(Self(value)),
+                    Some(value) => Err($MaxErr::with(Rejected::Value(value))),
+                    None => Err($MaxErr::with(Self::add_unsigned_operands(self.0, rhs))),
                 }
             }
 
@@ -273,8 +274,8 @@ macro_rules! constrained_int_impl {
             pub const fn try_sub(self, rhs: $SigInt) -> Result<Self, $Err<MIN, MAX>> {
                 match self.checked_sub(rhs) {
                     Some(this) => Ok(this),
-                    None if rhs.is_positive() => Err($Err::lower()),
-                    None => Err($Err::greater()),
+                    None if rhs.is_positive() => Err($Err::Lower($MinErr::with(Rejected::Sub(self.0, rhs)))),
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Sub(self.0, rhs)))),
                 }
             }
 
@@ -294,9 +295,10 @@ macro_rules! constrained_int_impl {
             /// ```
             #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn try_sub_unsigned(self, rhs: $UnsInt) -> Result<Self, $MinErr<MIN>> {
-                match self.checked_sub_unsigned(rhs) {
-                    Some(this) => Ok(this),
-                    None => Err($MinErr::new()),
+                match self.0.checked_sub_unsigned(rhs) {
+                    Some(value) if value >= MIN => Ok(Self(value)),
+                    Some(value) => Err($MinErr::with(Rejected::Value(value))),
+                    None => Err($MinErr::with(Self::sub_unsigned_operands(self.0, rhs))),
                 }
             }
 
@@ -322,7 +324,7 @@ macro_rules! constrained_int_impl {
             /// constrained = constrained.wrapping_add(-1);
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_add(mut self, mut rhs: $SigInt) -> Self {
                 (self, rhs) = match self.0.overflowing_add(rhs) {
                     (value, false) if value >= MIN && value <= MAX => return Self(value),
@@ -349,7 +351,7 @@ macro_rules! constrained_int_impl {
             #[doc = concat!("constrained = constrained.wrapping_add_unsigned(1_", stringify!($UnsInt), ");")]
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_add_unsigned(mut self, mut rhs: $UnsInt) -> Self {
                 (self, rhs) = match self.0.overflowing_add_unsigned(rhs) {
                     (value, false) if value <= MAX => return Self(value),
@@ -382,7 +384,7 @@ macro_rules! constrained_int_impl {
             /// constrained = constrained.wrapping_sub(-1);
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_sub(mut self, mut rhs: $SigInt) -> Self {
                 (self, rhs) = match self.0.overflowing_sub(rhs) {
                     (value, false) if value >= MIN && value <= MAX => return Self(value),
@@ -409,7 +411,7 @@ macro_rules! constrained_int_impl {
             #[doc = concat!("constrained = constrained.wrapping_sub_unsigned(1_", stringify!($UnsInt), ");")]
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_sub_unsigned(mut self, mut rhs: $UnsInt) -> Self {
                 (self, rhs) = match self.0.overflowing_sub_unsigned(rhs) {
                     (value, false) if value >= MIN => return Self(value),
@@ -447,7 +449,7 @@ macro_rules! constrained_int_impl {
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// assert_eq!(wrapped, false);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_add(self, rhs: $SigInt) -> (Self, bool) {
                 match self.0.overflowing_add(rhs) {
                     (value, false) if value >= MIN && value <= MAX => (Self(value), false),
@@ -479,7 +481,7 @@ macro_rules! constrained_int_impl {
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// assert_eq!(wrapped, true);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_add_unsigned(self, rhs: $UnsInt) -> (Self, bool) {
                 match self.0.overflowing_add_unsigned(rhs) {
                     (value, false) if value <= MAX => (Self(value), false),
@@ -515,7 +517,7 @@ macro_rules! constrained_int_impl {
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// assert_eq!(wrapped, false);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_sub(self, rhs: $SigInt) -> (Self, bool) {
                 match self.0.overflowing_sub(rhs) {
                     (value, false) if value >= MIN && value <= MAX => (Self(value), false),
@@ -547,7 +549,7 @@ macro_rules! constrained_int_impl {
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// assert_eq!(wrapped, true);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_sub_unsigned(self, rhs: $UnsInt) -> (Self, bool) {
                 match self.0.overflowing_sub_unsigned(rhs) {
                     (value, false) if value >= MIN => (Self(value), false),
@@ -556,6 +558,460 @@ macro_rules! constrained_int_impl {
                 }
             }
 
+            /// Saturating integer multiplication. Computes `self * rhs`, saturating the
+            /// result at the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Saturates at upper bound.
+            /// constrained = constrained.saturating_mul(2);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            ///
+            /// // Saturates at lower bound.
+            /// constrained = constrained.saturating_mul(-2);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_mul(self, mut rhs: $SigInt) -> Self {
+                rhs = self.0.saturating_mul(rhs);
+                Self::saturating_new_unguarded(rhs)
+            }
+
+            /// Checked integer multiplication. Computes `self * rhs`, returning [`None`]
+            /// if result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_mul(2), None);
+            /// // Below lower bound.
+            /// assert_eq!(constrained.checked_mul(-2), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_mul(self, rhs: $SigInt) -> Option<Self> {
+                // Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer multiplication. Computes `self * rhs`, returning an
+            /// error if the result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert!(constrained.try_mul(2).is_err());
+            /// // Below lower bound.
+            /// assert!(constrained.try_mul(-2).is_err());
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_mul(self, rhs: $SigInt) -> Result<Self, $Err<MIN, MAX>> {
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Self::new_unguarded(value),
+                    None if self.0.is_negative() == rhs.is_negative() => {
+                        Err($Err::Greater($MaxErr::with(Rejected::Mul(self.0, rhs))))
+                    }
+                    None => Err($Err::Lower($MinErr::with(Rejected::Mul(self.0, rhs)))),
+                }
+            }
+
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping the
+            /// result around the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `self * rhs` modulo the range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 4>;")]
+            ///
+            /// let mut constrained = Constrained::new(4).unwrap();
+            /// // Wraps around upper bound.
+            /// constrained = constrained.wrapping_mul(3);
+            /// assert_eq!(constrained.get(), 2);
+            ///
+            /// constrained.set(-4).unwrap();
+            /// // Wraps around lower bound.
+            /// constrained = constrained.wrapping_mul(3);
+            /// assert_eq!(constrained.get(), -2);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_mul(self, rhs: $SigInt) -> Self {
+                match self.0.overflowing_mul(rhs) {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    (_, true) => Self::overflowed_mul(self.0, rhs),
+                }
+            }
+
+            /// Wrapping (modular) multiplication, indicating if result was wrapped around.
+            ///
+            /// Computes `self * rhs`, wrapping the result around the range's inclusive
+            /// bounds. If a wrapping multiplication would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 4>;")]
+            ///
+            /// let mut constrained = Constrained::new(4).unwrap();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the upper bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_mul(3);
+            /// assert_eq!(constrained.get(), 2);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_mul(self, rhs: $SigInt) -> (Self, bool) {
+                match self.0.overflowing_mul(rhs) {
+                    (value, false) if value >= MIN && value <= MAX => (Self(value), false),
+                    (value, false) => (Self::wrap(value), true),
+                    (_, true) => (Self::overflowed_mul(self.0, rhs), true),
+                }
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating
+            /// the result at the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Saturates at upper bound.
+            /// constrained = constrained.saturating_pow(2);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            ///
+            /// constrained = Constrained::new_min();
+            /// // Saturates at lower bound.
+            /// constrained = constrained.saturating_pow(3);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_pow(self, exp: u32) -> Self {
+                let value = self.0.saturating_pow(exp);
+                Self::saturating_new_unguarded(value)
+            }
+
+            /// Checked integer exponentiation. Computes `self.pow(exp)`, returning
+            /// [`None`] if result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_pow(2), None);
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Below lower bound.
+            /// assert_eq!(constrained.checked_pow(3), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_pow(exp) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer exponentiation. Computes `self.pow(exp)`, returning an
+            /// error if the result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert!(constrained.try_pow(2).is_err());
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Below lower bound.
+            /// assert!(constrained.try_pow(3).is_err());
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_pow(self, exp: u32) -> Result<Self, $Err<MIN, MAX>> {
+                match self.0.checked_pow(exp) {
+                    Some(value) => Self::new_unguarded(value),
+                    None if self.0.is_negative() && exp % 2 == 1 => {
+                        Err($Err::Lower($MinErr::with(Rejected::Pow(self.0, exp))))
+                    }
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Pow(self.0, exp)))),
+                }
+            }
+
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping
+            /// the result around the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `self.pow(exp)` modulo the range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 4>;")]
+            ///
+            /// let mut constrained = Constrained::new(4).unwrap();
+            /// // Wraps around upper bound.
+            /// constrained = constrained.wrapping_pow(2);
+            /// assert_eq!(constrained.get(), -4);
+            ///
+            /// constrained.set(-3).unwrap();
+            /// // Wraps around lower bound.
+            /// constrained = constrained.wrapping_pow(3);
+            /// assert_eq!(constrained.get(), 3);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_pow(self, exp: u32) -> Self {
+                match self.0.overflowing_pow(exp) {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    (_, true) => Self::overflowed_pow(self.0, exp),
+                }
+            }
+
+            /// Wrapping (modular) exponentiation, indicating if result was wrapped around.
+            ///
+            /// Computes `self.pow(exp)`, wrapping the result around the range's inclusive
+            /// bounds. If a wrapping exponentiation would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 4>;")]
+            ///
+            /// let mut constrained = Constrained::new(4).unwrap();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the upper bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_pow(2);
+            /// assert_eq!(constrained.get(), -4);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                match self.0.overflowing_pow(exp) {
+                    (value, false) if value >= MIN && value <= MAX => (Self(value), false),
+                    (value, false) => (Self::wrap(value), true),
+                    (_, true) => (Self::overflowed_pow(self.0, exp), true),
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating the result
+            /// at the range's inclusive bounds, or returning [`None`] if `rhs == 0`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Saturates at upper bound.
+            /// assert_eq!(constrained.saturating_div(-1), Some(Constrained::new_max()));
+            /// // Division by zero.
+            /// assert_eq!(constrained.saturating_div(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_div(self, rhs: $SigInt) -> Option<Self> {
+                if rhs == 0 {
+                    return None;
+                }
+                Some(Self::saturating_new_unguarded(self.0.saturating_div(rhs)))
+            }
+
+            /// Checked integer division. Computes `self / rhs`, returning [`None`] if
+            /// `rhs == 0`, the division overflows or the result is out of the range's
+            /// inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_div(2).unwrap().get(), ", stringify!($max), " / 2);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_div(0), None);
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_div(-1), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_div(self, rhs: $SigInt) -> Option<Self> {
+                // Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_div(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning [`None`] if
+            /// `rhs == 0`, the division overflows or the result is out of the range's
+            /// inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.checked_rem(100).unwrap().get(), ", stringify!($min), " % 100);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_rem(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_rem(self, rhs: $SigInt) -> Option<Self> {
+                // Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_rem(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked Euclidean division. Computes `self.div_euclid(rhs)`, returning
+            /// [`None`] if `rhs == 0`, the division overflows or the result is out of the
+            /// range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new(-7).unwrap();
+            /// assert_eq!(constrained.checked_div_euclid(2).unwrap().get(), -4);
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_div_euclid(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_div_euclid(self, rhs: $SigInt) -> Option<Self> {
+                // Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_div_euclid(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked Euclidean remainder. Computes `self.rem_euclid(rhs)`, returning
+            /// [`None`] if `rhs == 0`, the division overflows or the result is out of the
+            /// range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new(-7).unwrap();
+            /// assert_eq!(constrained.checked_rem_euclid(2).unwrap().get(), 1);
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_rem_euclid(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_rem_euclid(self, rhs: $SigInt) -> Option<Self> {
+                // Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_rem_euclid(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer division. Computes `self / rhs`, returning [`None`] if
+            /// `rhs == 0`, or an error if the result is out of the range's inclusive
+            /// bounds.
+            ///
+            #[doc = concat!("The overflowing `", stringify!($SigInt), "::MIN / -1` division is reported as a value")]
+            /// greater than the range's upper bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.try_div(2).unwrap()?.get(), ", stringify!($max), " / 2);")]
+            /// // Division by zero.
+            /// assert!(constrained.try_div(0).is_none());
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Above upper bound.
+            /// assert!(constrained.try_div(-1).unwrap().is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_div(self, rhs: $SigInt) -> Option<Result<Self, $Err<MIN, MAX>>> {
+                if rhs == 0 {
+                    return None;
+                }
+                match self.0.checked_div(rhs) {
+                    Some(value) => Some(Self::new_unguarded(value)),
+                    // Only `<$SigInt>::MIN / -1` overflows, its result is above `MAX`.
+                    None => Some(Err($Err::Greater($MaxErr::with(Rejected::Div(self.0, rhs))))),
+                }
+            }
+
             /// Returns a number representing sign of `self`.
             ///
             ///  - `0` if the number is zero
@@ -578,73 +1034,368 @@ macro_rules! constrained_int_impl {
             /// constrained.set(0).unwrap();
             /// assert_eq!(constrained.signum(), 0);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline(always)]
             pub const fn signum(self) -> $SigInt {
                 self.0.signum()
             }
 
-            /// Returns `true` if `self` is negative and `false` if the number is zero or
-            /// positive.
+            /// Returns `true` if `self` is negative and `false` if the number is zero or
+            /// positive.
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// assert!(constrained.is_negative());
+            /// ```
+            #[inline(always)]
+            pub const fn is_negative(self) -> bool {
+                self.0.is_negative()
+            }
+
+            /// Returns `true` if `self` is positive and `false` if the number is zero or
+            /// negative.
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// assert!(constrained.is_positive());
+            /// ```
+            #[inline(always)]
+            pub const fn is_positive(self) -> bool {
+                self.0.is_positive()
+            }
+
+            #[doc = concat!("Checked absolute value. Computes `", stringify!($SigInt), "::abs()`, ")]
+            /// returning None if it's greater than `MAX`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-10, 8>;")]
+            ///
+            /// let mut constrained = Constrained::new(-5).unwrap();
+            /// // Lower than `MAX`.
+            /// constrained = constrained.checked_abs().unwrap();
+            /// assert_eq!(constrained.get(), 5);
+            ///
+            /// // Greater than `MAX`.
+            /// constrained.set(-9).unwrap();
+            /// assert_eq!(constrained.checked_abs(), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_abs(self) -> Option<Self> {
+                match self.0.checked_abs() {
+                    Some(value) if value <= MAX => Some(Self(value)),
+                    _ => None,
+                }
+            }
+
+            #[doc = concat!("Saturating absolute value. Computes `", stringify!($SigInt), "::abs()`, ")]
+            /// returning `MAX` if it's greater than `MAX`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-10, 8>;")]
+            ///
+            /// let mut constrained = Constrained::new(-5).unwrap();
+            /// // Lower than `MAX`.
+            /// constrained = constrained.saturating_abs();
+            /// assert_eq!(constrained.get(), 5);
+            ///
+            /// // Saturates at upper bound.
+            /// constrained.set(-9).unwrap();
+            /// constrained = constrained.saturating_abs();
+            /// assert_eq!(constrained.get(), 8);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_abs(self) -> Self {
+                let value = self.0.saturating_abs();
+                Self::saturating_new_unguarded(value)
+            }
+
+            #[doc = concat!("Wrapping (modular) absolute value. Computes `", stringify!($SigInt), "::abs()`, ")]
+            /// wrapping the result around the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-10, 8>;")]
+            ///
+            /// let mut constrained = Constrained::new(-5).unwrap();
+            /// // Lower than `MAX`.
+            /// constrained = constrained.wrapping_abs();
+            /// assert_eq!(constrained.get(), 5);
+            ///
+            /// // Wraps around the upper bound.
+            /// constrained.set(-9).unwrap();
+            /// constrained = constrained.wrapping_abs();
+            /// assert_eq!(constrained.get(), -10);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_abs(self) -> Self {
+                if self.0.is_negative() {
+                    self.wrapping_neg()
+                } else {
+                    self
+                }
+            }
+
+            #[doc = concat!("Computes the absolute value of `self` without any wrapping or panicking, ")]
+            #[doc = concat!("returning it as a `", stringify!($UnsInt), "`.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<{ ", stringify!($SigInt), "::MIN }, 0>;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            #[doc = concat!("assert_eq!(constrained.unsigned_abs(), ", stringify!($SigInt), "::MIN.unsigned_abs());")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn unsigned_abs(self) -> $UnsInt {
+                self.0.unsigned_abs()
+            }
+
+            /// Computes the absolute difference between `self` and `rhs`.
+            ///
+            #[doc = concat!("The result is returned as a `", stringify!($UnsInt), "`, so this operation can't overflow.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let min = Constrained::new_min();
+            /// let max = Constrained::new_max();
+            ///
+            /// assert_eq!(min.abs_diff(max), 253);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn abs_diff(self, rhs: Self) -> $UnsInt {
+                self.0.abs_diff(rhs.0)
+            }
+
+            /// Computes the distance between `self` and `rhs`, which is their absolute
+            /// difference.
+            ///
+            #[doc = concat!("The result is returned as a `", stringify!($UnsInt), "`, so this operation can't overflow.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let min = Constrained::new_min();
+            /// let max = Constrained::new_max();
+            ///
+            /// assert_eq!(min.distance(max), 253);
+            /// assert_eq!(max.distance(min), 253);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn distance(self, rhs: Self) -> $UnsInt {
+                self.0.abs_diff(rhs.0)
+            }
+
+            /// Checked negation. Computes `-self`, returning [`None`] if result is out
+            /// of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 10>;")]
+            ///
+            /// let mut constrained = Constrained::new(3).unwrap();
+            /// // Greater than `MIN`.
+            /// constrained = constrained.checked_neg().unwrap();
+            /// assert_eq!(constrained.get(), -3);
+            ///
+            /// // Below lower bound.
+            /// constrained.set(8).unwrap();
+            /// assert_eq!(constrained.checked_neg(), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_neg(self) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_neg() {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Saturating negation. Computes `-self`, saturating the result at the
+            /// range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 10>;")]
+            ///
+            /// let mut constrained = Constrained::new(8).unwrap();
+            /// // Saturates at lower bound.
+            /// constrained = constrained.saturating_neg();
+            /// assert_eq!(constrained.get(), -5);
+            ///
+            /// // Within the range's bounds.
+            /// constrained = constrained.saturating_neg();
+            /// assert_eq!(constrained.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_neg(self) -> Self {
+                let value = self.0.saturating_neg();
+                Self::saturating_new_unguarded(value)
+            }
+
+            /// Fallible negation. Computes `-self`, returning an error if the result
+            /// is out of the range's inclusive bounds.
             ///
             /// # Example
+            ///
             /// ```
             #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
             ///
-            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 10>;")]
             ///
-            /// let mut constrained = Constrained::new_min();
-            /// assert!(constrained.is_negative());
+            /// let constrained = Constrained::new(3).unwrap();
+            /// // Within the range's bounds.
+            /// assert_eq!(constrained.try_neg().unwrap().get(), -3);
+            ///
+            /// let constrained = Constrained::new(8).unwrap();
+            /// // Below lower bound.
+            /// assert!(constrained.try_neg().is_err());
             /// ```
-            #[inline(always)]
-            pub const fn is_negative(self) -> bool {
-                self.0.is_negative()
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_neg(self) -> Result<Self, $Err<MIN, MAX>> {
+                match self.0.checked_neg() {
+                    Some(value) => Self::new_unguarded(value),
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Neg(self.0)))),
+                }
             }
 
-            /// Returns `true` if `self` is positive and `false` if the number is zero or
-            /// negative.
+            /// Wrapping (modular) negation. Computes `-self`, wrapping the result
+            /// around the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `-self` modulo the range's size.
             ///
             /// # Example
+            ///
             /// ```
             #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
             ///
-            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 10>;")]
             ///
-            /// let mut constrained = Constrained::new_max();
-            /// assert!(constrained.is_positive());
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// // Wraps around the lower bound.
+            /// constrained = constrained.wrapping_neg();
+            /// assert_eq!(constrained.get(), 9);
             /// ```
-            #[inline(always)]
-            pub const fn is_positive(self) -> bool {
-                self.0.is_positive()
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_neg(self) -> Self {
+                match self.0.overflowing_neg() {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    (_, true) => Self::overflowed_neg(self.0),
+                }
             }
 
-            #[doc = concat!("Checked absolute value. Computes `", stringify!($SigInt), "::abs()`, ")]
-            /// returning None if it's greater than `MAX`.
+            /// Wrapping (modular) negation, indicating if result was wrapped around.
+            ///
+            /// Computes `-self`, wrapping the result around the range's inclusive
+            /// bounds. If a wrapping negation would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
             ///
             /// # Example
             ///
             /// ```
             #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
             ///
-            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-10, 8>;")]
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<-5, 10>;")]
             ///
-            /// let mut constrained = Constrained::new(-5).unwrap();
-            /// // Lower than `MAX`.
-            /// constrained = constrained.checked_abs().unwrap();
-            /// assert_eq!(constrained.get(), 5);
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// let mut wrapped: bool;
             ///
-            /// // Greater than `MAX`.
-            /// constrained.set(-9).unwrap();
-            /// assert_eq!(constrained.checked_abs(), None);
+            /// // Wraps around the lower bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_neg();
+            /// assert_eq!(constrained.get(), 9);
+            /// assert_eq!(wrapped, true);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
-            pub const fn checked_abs(self) -> Option<Self> {
-                match self.0.checked_abs() {
-                    Some(value) if value <= MAX => Some(Self(value)),
-                    _ => None,
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                match self.0.overflowing_neg() {
+                    (value, false) if value >= MIN && value <= MAX => (Self(value), false),
+                    (value, false) => (Self::wrap(value), true),
+                    (_, true) => (Self::overflowed_neg(self.0), true),
+                }
+            }
+
+            /// Wrapping division backing the `Div` implementation of `Wrapping`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Wrapping`.
+            #[must_use]
+            pub(crate) const fn wrapping_div_op(self, rhs: $SigInt) -> Self {
+                match self.0.overflowing_div(rhs) {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    // Only `<$SigInt>::MIN / -1` overflows, which is equal to `-self`.
+                    (_, true) => Self::overflowed_neg(self.0),
+                }
+            }
+
+            /// Wrapping remainder backing the `Rem` implementation of `Wrapping`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Wrapping`.
+            #[must_use]
+            pub(crate) const fn wrapping_rem_op(self, rhs: $SigInt) -> Self {
+                // `<$SigInt>::MIN % -1` is zero, which is what `wrapping_rem` returns.
+                match self.0.wrapping_rem(rhs) {
+                    value if value >= MIN && value <= MAX => Self(value),
+                    value => Self::wrap(value),
                 }
             }
 
+            /// Saturating division backing the `Div` implementation of `Saturating`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Saturating`.
+            #[must_use]
+            pub(crate) const fn saturating_div_op(self, rhs: $SigInt) -> Self {
+                Self::saturating_new_unguarded(self.0.saturating_div(rhs))
+            }
+
+            /// Saturating remainder backing the `Rem` implementation of `Saturating`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Saturating`.
+            #[must_use]
+            pub(crate) const fn saturating_rem_op(self, rhs: $SigInt) -> Self {
+                Self::saturating_new_unguarded(self.0.wrapping_rem(rhs))
+            }
+
             /// Wraps the value around the range's upper bound.
             ///
             /// Caller must ensure that `value` is greater than `MAX`, or else there will
@@ -722,11 +1473,11 @@ macro_rules! constrained_int_impl {
             /// result as the first value. Computes the remaining from the wrapped value
             /// and returns it in the second position.
             ///
-            /// Caller must ensure that `value` is greater than 0, or else there will be
-            /// an unexpected overflow.
+            /// Caller must ensure that `value` is not negative, or else there will be
+            /// an unexpected overflow. It is zero when adding `<$SigInt>::MIN` to itself.
             #[must_use]
             const fn wrap_around_min_over(mut value: $SigInt) -> (Self, $SigInt) {
-                debug_assert!(value > 0, "value must greater than 0");
+                debug_assert!(value >= 0, "value must not be negative");
                 value = <$SigInt>::abs_diff(<$SigInt>::MAX, value) as $SigInt;
                 // TODO: No conditional compilation based on constexpr evaluation yet.
                 if <$SigInt>::MIN < MIN {
@@ -827,6 +1578,158 @@ macro_rules! constrained_int_impl {
                 this.wrapping_sub_unsigned(rhs)
             }
 
+            /// Handles overflowed `overflowing_mul` calls from inner integer.
+            ///
+            /// `lhs` and `rhs` are the operands of the overflowed multiplication.
+            #[must_use]
+            const fn overflowed_mul(lhs: $SigInt, rhs: $SigInt) -> Self {
+                let lhs = Self::remainder_euclid(lhs);
+                let rhs = Self::remainder_euclid(rhs);
+                Self::from_remainder(Self::mul_remainders(lhs, rhs))
+            }
+
+            /// Handles overflowed `overflowing_pow` calls from inner integer.
+            ///
+            /// `base` and `exp` are the operands of the overflowed exponentiation.
+            #[must_use]
+            const fn overflowed_pow(base: $SigInt, exp: u32) -> Self {
+                let rem = Self::pow_remainders(Self::remainder_euclid(base), exp);
+                Self::from_remainder(rem)
+            }
+
+            /// Handles overflowed `overflowing_neg` calls from inner integer.
+            ///
+            /// `value` is the operand of the overflowed negation.
+            #[must_use]
+            const fn overflowed_neg(value: $SigInt) -> Self {
+                let rem = Self::remainder_euclid(value);
+                // The remainder of `-value` is the complement of the remainder of `value`.
+                let rem = if rem == 0 { 0 } else { Self::range_size() - rem };
+                Self::from_remainder(rem)
+            }
+
+            /// Wraps any `value` into the range, returning the only value contained by
+            /// the range that is congruent to `value` modulo the range's size.
+            #[must_use]
+            const fn wrap(value: $SigInt) -> Self {
+                Self::from_remainder(Self::remainder_euclid(value))
+            }
+
+            /// Returns the operands of `lhs + rhs`, keeping `rhs` unsigned only if it
+            /// is not representable by the primitive.
+            #[must_use]
+            const fn add_unsigned_operands(lhs: $SigInt, rhs: $UnsInt) -> Rejected {
+                if rhs <= <$SigInt>::MAX as $UnsInt {
+                    Rejected::Add(lhs, rhs as $SigInt)
+                } else {
+                    Rejected::AddUnsigned(lhs, rhs as u128)
+                }
+            }
+
+            /// Returns the operands of `lhs - rhs`, keeping `rhs` unsigned only if it
+            /// is not representable by the primitive.
+            #[must_use]
+            const fn sub_unsigned_operands(lhs: $SigInt, rhs: $UnsInt) -> Rejected {
+                if rhs <= <$SigInt>::MAX as $UnsInt {
+                    Rejected::Sub(lhs, rhs as $SigInt)
+                } else {
+                    Rejected::SubUnsigned(lhs, rhs as u128)
+                }
+            }
+
+            /// Returns `(lhs + rhs) / 2` rounded towards zero, without overflowing.
+            #[must_use]
+            const fn half_sum(lhs: $SigInt, rhs: $SigInt) -> $SigInt {
+                // Shifts round down both halves, and the lost bits only add up
+                // if they are both set.
+                let half = (lhs >> 1) + (rhs >> 1) + (lhs & rhs & 1);
+                // Odd sums were rounded down, which is away from zero if negative.
+                if half < 0 && (lhs ^ rhs) & 1 == 1 {
+                    half + 1
+                } else {
+                    half
+                }
+            }
+
+            /// Returns the only value contained by the range that has `rem` as its
+            /// euclidean remainder by the range's size.
+            ///
+            /// Caller must ensure that `rem` is lower than `range_size()`, or else there
+            /// will be an unexpected overflow.
+            #[must_use]
+            const fn from_remainder(rem: $UnsInt) -> Self {
+                debug_assert!(rem < Self::range_size(), "rem must be lower than range size");
+                let min = Self::remainder_euclid(MIN);
+                let offset = if rem >= min {
+                    rem - min
+                } else {
+                    Self::range_size() - (min - rem)
+                };
+                // Can't overflow since `offset` is always lower than `range_size()`.
+                Self(MIN.wrapping_add_unsigned(offset))
+            }
+
+            /// Computes `(lhs + rhs) % range_size()` without overflowing.
+            ///
+            /// Caller must ensure that both `lhs` and `rhs` are lower than `range_size()`,
+            /// or else there will be an unexpected overflow.
+            #[must_use]
+            const fn add_remainders(lhs: $UnsInt, rhs: $UnsInt) -> $UnsInt {
+                let complement = Self::range_size() - rhs;
+                if lhs >= complement {
+                    lhs - complement
+                } else {
+                    lhs + rhs
+                }
+            }
+
+            /// Computes `(lhs * rhs) % range_size()` without overflowing, by doubling
+            /// and adding remainders.
+            ///
+            /// Caller must ensure that both `lhs` and `rhs` are lower than `range_size()`,
+            /// or else there will be an unexpected overflow.
+            #[must_use]
+            const fn mul_remainders(mut lhs: $UnsInt, mut rhs: $UnsInt) -> $UnsInt {
+                let mut rem = 0;
+                while rhs > 0 {
+                    if rhs & 1 == 1 {
+                        rem = Self::add_remainders(rem, lhs);
+                    }
+                    lhs = Self::add_remainders(lhs, lhs);
+                    rhs >>= 1;
+                }
+                rem
+            }
+
+            /// Computes `base.pow(exp) % range_size()` without overflowing, by squaring
+            /// and multiplying remainders.
+            ///
+            /// Caller must ensure that `base` is lower than `range_size()`, or else there
+            /// will be an unexpected overflow.
+            #[must_use]
+            const fn pow_remainders(mut base: $UnsInt, mut exp: u32) -> $UnsInt {
+                let mut rem = Self::remainder(1);
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        rem = Self::mul_remainders(rem, base);
+                    }
+                    base = Self::mul_remainders(base, base);
+                    exp >>= 1;
+                }
+                rem
+            }
+
+            /// Computes the euclidean remainder of signed `value` by the range's size.
+            #[must_use]
+            const fn remainder_euclid(value: $SigInt) -> $UnsInt {
+                let rem = Self::remainder(value.unsigned_abs());
+                if value >= 0 || rem == 0 {
+                    rem
+                } else {
+                    Self::range_size() - rem
+                }
+            }
+
             /// Computes the remainder of `value` by the range's size.
             #[must_use]
             const fn remainder(value: $UnsInt) -> $UnsInt {
@@ -871,7 +1774,7 @@ macro_rules! constrained_int_impl {
 // Defines mods, containers, errors, impls, tests and default doc values for unsigned integers.
 macro_rules! constrained_int_def_impl {
     ($({ $SigInt:ty, $UnsInt:ty, $sint_md:ident, $uint_md:ident,
-         $Ty:ident, $Err:ident, $MinErr:ident, $MaxErr:ident }),+ $(,)*
+         $Ty:ident, $Err:ident, $MinErr:ident, $MaxErr:ident, $ParseErr:ident }),+ $(,)*
     ) => {$(
         #[doc = concat!("Container and Error types for a range constrained [`prim@", stringify!($SigInt), "`].")]
         pub mod $sint_md {
@@ -883,6 +1786,50 @@ macro_rules! constrained_int_def_impl {
                 $SigInt, $UnsInt, $sint_md, $Ty, $Err, $MinErr, $MaxErr, -127..=126
             }
 
+            constrained_ops_impl! {
+                $SigInt, $Ty, $Err
+            }
+
+            constrained_bits_impl! {
+                $SigInt, $sint_md, $Ty, $Err
+            }
+
+            constrained_assign_impl! {
+                $SigInt, $sint_md, $Ty, $Err, -127..=126,
+                { $SigInt, new_max, 1, saturating_add_assign => saturating_add,
+                  wrapping_add_assign => wrapping_add, checked_add_assign => try_add },
+                { $UnsInt, new_max, 1, saturating_add_unsigned_assign => saturating_add_unsigned,
+                  wrapping_add_unsigned_assign => wrapping_add_unsigned, checked_add_unsigned_assign => try_add_unsigned },
+                { $SigInt, new_min, 1, saturating_sub_assign => saturating_sub,
+                  wrapping_sub_assign => wrapping_sub, checked_sub_assign => try_sub },
+                { $UnsInt, new_min, 1, saturating_sub_unsigned_assign => saturating_sub_unsigned,
+                  wrapping_sub_unsigned_assign => wrapping_sub_unsigned, checked_sub_unsigned_assign => try_sub_unsigned },
+                { $SigInt, new_max, 2, saturating_mul_assign => saturating_mul,
+                  wrapping_mul_assign => wrapping_mul, checked_mul_assign => try_mul },
+                { u32, new_max, 2, saturating_pow_assign => saturating_pow,
+                  wrapping_pow_assign => wrapping_pow, checked_pow_assign => try_pow },
+            }
+
+            constrained_bounded_impl! {
+                $SigInt, $Ty, $Err
+            }
+
+            constrained_sample_impl! {
+                $SigInt, $Ty
+            }
+
+            constrained_iter_impl! {
+                $SigInt, $UnsInt, $sint_md, $Ty, $Err, -127..=126
+            }
+
+            constrained_parse_impl! {
+                $SigInt, $sint_md, $Ty, $Err, $MinErr, $MaxErr, $ParseErr, -127..=126, (-128, 127)
+            }
+
+            constrained_neg_impl! {
+                $SigInt, $Ty
+            }
+
             #[cfg(test)]
             mod tests_int_common {
                 tests_common! {
@@ -890,6 +1837,60 @@ macro_rules! constrained_int_def_impl {
                 }
             }
 
+            #[cfg(test)]
+            mod tests_int_ops {
+                tests_ops! {
+                    $SigInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_int_bits {
+                tests_bits! {
+                    $SigInt, super, $Ty, $Err
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_int_bounded {
+                tests_bounded! {
+                    $SigInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_int_assign {
+                tests_assign! {
+                    super, $Ty,
+                    { $SigInt, -12..=12, saturating_add_assign => saturating_add,
+                      wrapping_add_assign => wrapping_add, checked_add_assign => try_add },
+                    { $UnsInt, 0..=12, saturating_add_unsigned_assign => saturating_add_unsigned,
+                      wrapping_add_unsigned_assign => wrapping_add_unsigned, checked_add_unsigned_assign => try_add_unsigned },
+                    { $SigInt, -12..=12, saturating_sub_assign => saturating_sub,
+                      wrapping_sub_assign => wrapping_sub, checked_sub_assign => try_sub },
+                    { $UnsInt, 0..=12, saturating_sub_unsigned_assign => saturating_sub_unsigned,
+                      wrapping_sub_unsigned_assign => wrapping_sub_unsigned, checked_sub_unsigned_assign => try_sub_unsigned },
+                    { $SigInt, -12..=12, saturating_mul_assign => saturating_mul,
+                      wrapping_mul_assign => wrapping_mul, checked_mul_assign => try_mul },
+                    { u32, 0..=12, saturating_pow_assign => saturating_pow,
+                      wrapping_pow_assign => wrapping_pow, checked_pow_assign => try_pow },
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_int_iter {
+                tests_iter! {
+                    $SigInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_int_parse {
+                tests_parse! {
+                    $SigInt, super, $Ty, $ParseErr
+                }
+            }
+
             #[cfg(test)]
             mod tests_int_specific {
                 tests_int! {
@@ -909,7 +1910,7 @@ macro_rules! tests_int {
         use crate::proptest::$sint_md::{SigCnstGen, SigRhs, SigRhsGen};
         use crate::proptest::$uint_md::{UnsRhs, UnsRhsGen};
         use ::core::fmt::Debug;
-        use $ty_path::{guard_construction, $Err, $MaxErr, $MinErr, $Ty};
+        use $ty_path::{guard_construction, $Err, $MaxErr, $MinErr, $Ty, Rejected};
 
         #[test]
         fn signum() {
@@ -965,6 +1966,351 @@ macro_rules! tests_int {
             assert_eq!(constrained.checked_abs(), None);
         }
 
+        #[test]
+        fn neg_small_range() {
+            type Cnst = $Ty<-5, 10>;
+            let (min, max) = (Cnst::MIN as i128, Cnst::MAX as i128);
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                let neg = -(value as i128);
+                let expected = (neg - min).rem_euclid(max - min + 1) + min;
+                let in_range = (min..=max).contains(&neg);
+
+                assert_eq!(cnst.wrapping_neg().get() as i128, expected);
+                assert_eq!(cnst.overflowing_neg(), (cnst.wrapping_neg(), !in_range));
+                assert_eq!(cnst.checked_neg().is_some(), in_range);
+                assert_eq!(cnst.saturating_neg().get() as i128, neg.clamp(min, max));
+                match cnst.try_neg() {
+                    Ok(cnst) => assert_eq!(cnst.get() as i128, neg),
+                    Err(err) if neg < min => assert_eq!(err, $Err::lower_value(neg as $SigInt)),
+                    Err(err) => assert_eq!(err, $Err::greater_value(neg as $SigInt)),
+                }
+
+                let abs = (value as i128).abs();
+                let expected = (abs - min).rem_euclid(max - min + 1) + min;
+                assert_eq!(cnst.wrapping_abs().get() as i128, expected);
+                assert_eq!(cnst.saturating_abs().get() as i128, abs.clamp(min, max));
+                assert_eq!(cnst.unsigned_abs(), value.unsigned_abs());
+
+                for rhs in Cnst::range() {
+                    assert_eq!(cnst.abs_diff(Cnst::new(rhs).unwrap()), value.abs_diff(rhs));
+                }
+            }
+        }
+
+        #[test]
+        fn unsigned_overflow_operands() {
+            type Cnst = $Ty<{ <$SigInt>::MIN }, { <$SigInt>::MAX - 1 }>;
+            let (min, max) = (Cnst::new_min(), Cnst::new_max());
+
+            let err = max.try_add_unsigned(2).unwrap_err();
+            assert_eq!(err.operands(), Some((<$SigInt>::MAX - 1, 2)));
+            assert_eq!(err.value(), None);
+
+            let err = min.try_sub_unsigned(1).unwrap_err();
+            assert_eq!(err.operands(), Some((<$SigInt>::MIN, 1)));
+            assert_eq!(err.value(), None);
+
+            // Unsigned operands not representable by the primitive are only displayed.
+            let rhs = <$UnsInt>::MAX;
+            let err = max.try_add_unsigned(rhs).unwrap_err();
+            assert_eq!(err, $MaxErr::with(Rejected::AddUnsigned(<$SigInt>::MAX - 1, rhs as u128)));
+            assert_eq!(err.operands(), None);
+
+            let err = min.try_sub_unsigned(rhs).unwrap_err();
+            assert_eq!(err, $MinErr::with(Rejected::SubUnsigned(<$SigInt>::MIN, rhs as u128)));
+            assert_eq!(err.operands(), None);
+        }
+
+        #[test]
+        fn neg_overflow() {
+            type Cnst = $Ty<{ <$SigInt>::MIN }, 0>;
+            let cnst = Cnst::new_min();
+            // `-MIN` is congruent to `-1` modulo the range's size.
+            let expected = $Ty(-1);
+
+            assert_eq!(cnst.checked_neg(), None);
+            assert_eq!(cnst.saturating_neg(), Cnst::new_max());
+            let err = cnst.try_neg().unwrap_err();
+            assert_eq!(err, $Err::Greater($MaxErr::with(Rejected::Neg(<$SigInt>::MIN))));
+            assert_eq!(err.value(), None);
+            assert_eq!(cnst.wrapping_neg(), expected);
+            assert_eq!(cnst.overflowing_neg(), (expected, true));
+            assert_eq!(cnst.wrapping_abs(), expected);
+            assert_eq!(cnst.saturating_abs(), Cnst::new_max());
+            assert_eq!(cnst.unsigned_abs(), <$SigInt>::MIN.unsigned_abs());
+        }
+
+        #[test]
+        fn neg_op() {
+            let cnst = $Ty::<-5, 10>::new(3).unwrap();
+            assert_eq!(-cnst, $Ty(-3));
+            assert_eq!(-&cnst, $Ty(-3));
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "value must be greater or equal to -5")]
+        fn neg_op_unbounded() {
+            let _ = -$Ty::<-5, 10>::new(8).unwrap();
+        }
+
+        #[test]
+        #[cfg(not(debug_assertions))]
+        fn neg_op_unbounded() {
+            let cnst = $Ty::<-5, 10>::new(8).unwrap();
+            assert_eq!(-cnst, cnst.wrapping_neg());
+        }
+
+        #[test]
+        fn midpoint_rounds_towards_zero() {
+            type Cnst = $Ty<-9, 9>;
+
+            for lhs in Cnst::range() {
+                for rhs in Cnst::range() {
+                    let expected = (lhs as i128 + rhs as i128) / 2;
+                    let midpoint = Cnst::new(lhs).unwrap().midpoint(Cnst::new(rhs).unwrap());
+                    assert_eq!(midpoint.get() as i128, expected);
+                }
+            }
+
+            type WideTest = $Ty<{ <$SigInt>::MIN }, { <$SigInt>::MAX - 1 }>;
+            let (min, max) = (WideTest::new_min(), WideTest::new_max());
+            let next = WideTest::new(<$SigInt>::MIN + 1).unwrap();
+
+            assert_eq!(min.midpoint(max).get(), -1);
+            assert_eq!(min.midpoint(next), next);
+            let prev = WideTest::new(<$SigInt>::MAX - 2).unwrap();
+            assert_eq!(max.midpoint(prev), prev);
+        }
+
+        #[test]
+        fn wrapping_add_min_to_min() {
+            type Cnst = $Ty<{ <$SigInt>::MIN }, { <$SigInt>::MAX - 1 }>;
+
+            // The overflowed sum wraps to zero, and the range size is `<$SigInt>::MAX`
+            // times two plus one, so `MIN * 2` is congruent to -1.
+            let cnst = Cnst::new_min().wrapping_add(<$SigInt>::MIN);
+            assert_eq!(cnst.get(), -1);
+        }
+
+        #[test]
+        fn mul_small_range() {
+            type Cnst = $Ty<-4, 5>;
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for rhs in -10..=10 {
+                    let product = value * rhs;
+                    let mut expected = product;
+                    while expected > Cnst::MAX {
+                        expected -= Cnst::range_size() as $SigInt;
+                    }
+                    while expected < Cnst::MIN {
+                        expected += Cnst::range_size() as $SigInt;
+                    }
+                    let in_range = Cnst::range().contains(&product);
+
+                    assert_eq!(cnst.wrapping_mul(rhs).get(), expected);
+                    assert_eq!(cnst.overflowing_mul(rhs), ($Ty(expected), !in_range));
+                    assert_eq!(cnst.checked_mul(rhs).is_some(), in_range);
+                    assert_eq!(cnst.saturating_mul(rhs), Cnst::saturating_new(product));
+                    match cnst.try_mul(rhs) {
+                        Ok(cnst) => assert_eq!(cnst.get(), product),
+                        Err(err) if product < Cnst::MIN => {
+                            assert_eq!(err, $Err::lower_value(product))
+                        }
+                        Err(err) => assert_eq!(err, $Err::greater_value(product)),
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn pow_small_range() {
+            type Cnst = $Ty<-4, 5>;
+            let (min, max) = (Cnst::MIN as i128, Cnst::MAX as i128);
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for exp in 0..=6 {
+                    let power = (value as i128).pow(exp);
+                    let expected = (power - min).rem_euclid(max - min + 1) + min;
+                    let in_range = (min..=max).contains(&power);
+
+                    assert_eq!(cnst.wrapping_pow(exp).get() as i128, expected);
+                    assert_eq!(cnst.overflowing_pow(exp), (cnst.wrapping_pow(exp), !in_range));
+                    assert_eq!(cnst.checked_pow(exp).is_some(), in_range);
+                    assert_eq!(cnst.saturating_pow(exp).get() as i128, power.clamp(min, max));
+                    match cnst.try_pow(exp) {
+                        Ok(cnst) => assert_eq!(cnst.get() as i128, power),
+                        Err(err) => {
+                            assert_eq!(matches!(err, $Err::Lower(_)), power < min);
+                            assert_eq!(err.value(), <$SigInt>::try_from(power).ok());
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn div_small_range() {
+            type Cnst = $Ty<-4, 5>;
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for rhs in -10..=10 {
+                    let filter =
+                        |value: Option<$SigInt>| value.filter(|v| Cnst::range().contains(v));
+                    let get = |cnst: Option<Cnst>| cnst.map(|cnst| cnst.get());
+
+                    assert_eq!(get(cnst.checked_div(rhs)), filter(value.checked_div(rhs)));
+                    assert_eq!(get(cnst.checked_rem(rhs)), filter(value.checked_rem(rhs)));
+                    assert_eq!(
+                        get(cnst.checked_div_euclid(rhs)),
+                        filter(value.checked_div_euclid(rhs))
+                    );
+                    assert_eq!(
+                        get(cnst.checked_rem_euclid(rhs)),
+                        filter(value.checked_rem_euclid(rhs))
+                    );
+
+                    if rhs == 0 {
+                        assert_eq!(cnst.saturating_div(rhs), None);
+                        assert_eq!(cnst.try_div(rhs), None);
+                    } else {
+                        let quotient = value / rhs;
+                        let remainder = value % rhs;
+                        let wrap = |mut value| {
+                            while value > Cnst::MAX {
+                                value -= Cnst::range_size() as $SigInt;
+                            }
+                            while value < Cnst::MIN {
+                                value += Cnst::range_size() as $SigInt;
+                            }
+                            value
+                        };
+
+                        assert_eq!(cnst.wrapping_div_op(rhs).get(), wrap(quotient));
+                        assert_eq!(cnst.wrapping_rem_op(rhs).get(), wrap(remainder));
+                        assert_eq!(cnst.saturating_div_op(rhs), Cnst::saturating_new(quotient));
+                        assert_eq!(cnst.saturating_rem_op(rhs), Cnst::saturating_new(remainder));
+                        assert_eq!(cnst.saturating_div(rhs), Some(Cnst::saturating_new(quotient)));
+                        match cnst.try_div(rhs).unwrap() {
+                            Ok(cnst) => assert_eq!(cnst.get(), quotient),
+                            Err(err) if quotient < Cnst::MIN => {
+                                assert_eq!(err, $Err::lower_value(quotient))
+                            }
+                            Err(err) => assert_eq!(err, $Err::greater_value(quotient)),
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn div_overflow() {
+            type Cnst = $Ty<{ <$SigInt>::MIN }, 0>;
+            let cnst = Cnst::new_min();
+
+            assert_eq!(cnst.checked_div(-1), None);
+            assert_eq!(cnst.checked_rem(-1), None);
+            assert_eq!(cnst.checked_div_euclid(-1), None);
+            assert_eq!(cnst.checked_rem_euclid(-1), None);
+            assert_eq!(cnst.saturating_div(-1), Some(Cnst::new_max()));
+            let err = $Err::Greater($MaxErr::with(Rejected::Div(<$SigInt>::MIN, -1)));
+            assert_eq!(cnst.try_div(-1), Some(Err(err)));
+            assert_eq!(err.operands(), Some((<$SigInt>::MIN, -1)));
+            assert_eq!(cnst.wrapping_div_op(-1), cnst.wrapping_neg());
+            assert_eq!(cnst.wrapping_rem_op(-1), $Ty(0));
+            assert_eq!(cnst.saturating_div_op(-1), Cnst::new_max());
+            assert_eq!(cnst.saturating_rem_op(-1), $Ty(0));
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to divide with overflow")]
+        fn div_op_overflow() {
+            let _ = $Ty::<{ <$SigInt>::MIN }, 0>::new_min() / -1;
+        }
+
+        #[test]
+        #[allow(clippy::modulo_one)]
+        #[should_panic(expected = "attempt to calculate the remainder with overflow")]
+        fn rem_op_overflow() {
+            let _ = $Ty::<{ <$SigInt>::MIN }, 0>::new_min() % -1;
+        }
+
+        #[test]
+        #[should_panic]
+        fn div_op_by_zero() {
+            let _ = $Ty::<-4, 5>::new_max().wrapping_div_op(0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn rem_op_by_zero() {
+            let _ = $Ty::<-4, 5>::new_max().saturating_rem_op(0);
+        }
+
+        fn assert_mul_consistency<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt>(
+            cnst: $Ty<MIN, MAX, DEF>,
+            rhs: SigRhs<{ <$SigInt>::MIN }, { <$SigInt>::MAX - 1 }>,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let (wrapped, overflowed) = cnst.overflowing_mul(rhs.get());
+            assert_eq!(wrapped, cnst.wrapping_mul(rhs.get()));
+
+            match cnst.checked_mul(rhs.get()) {
+                Some(product) => {
+                    assert!(!overflowed, "expected `overflowing_mul` to not overflow");
+                    assert_eq!(product, wrapped);
+                    assert_eq!(cnst.try_mul(rhs.get()), Ok(product));
+                    assert_eq!(cnst.saturating_mul(rhs.get()), product);
+                }
+                None => {
+                    assert!(overflowed, "expected `overflowing_mul` to overflow");
+                    assert!(cnst.try_mul(rhs.get()).is_err());
+                    let saturated = cnst.saturating_mul(rhs.get());
+                    assert!(saturated == $Ty::new_min() || saturated == $Ty::new_max());
+                }
+            }
+
+            // Wrapping multiplication distributes over wrapping addition.
+            let next = cnst.wrapping_mul(rhs.get() + 1);
+            assert_eq!(next, wrapped.wrapping_add(cnst.get()));
+        }
+
+        fn assert_pow_consistency<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt>(
+            cnst: $Ty<MIN, MAX, DEF>,
+            exp: u32,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let (wrapped, overflowed) = cnst.overflowing_pow(exp);
+            assert_eq!(wrapped, cnst.wrapping_pow(exp));
+
+            match cnst.checked_pow(exp) {
+                Some(power) => {
+                    assert!(!overflowed, "expected `overflowing_pow` to not overflow");
+                    assert_eq!(power, wrapped);
+                    assert_eq!(cnst.try_pow(exp), Ok(power));
+                    assert_eq!(cnst.saturating_pow(exp), power);
+                }
+                None => {
+                    assert!(overflowed, "expected `overflowing_pow` to overflow");
+                    assert!(cnst.try_pow(exp).is_err());
+                    let saturated = cnst.saturating_pow(exp);
+                    assert!(saturated == $Ty::new_min() || saturated == $Ty::new_max());
+                }
+            }
+
+            // Wrapping exponentiation is repeated wrapping multiplication.
+            let next = cnst.wrapping_pow(exp + 1);
+            assert_eq!(next, wrapped.wrapping_mul(cnst.get()));
+        }
+
         fn assert_add_bounded<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt>(
             rhs: SigRhs<{ 0 }, { $Ty::<MIN, MAX, DEF>::range_size_signed() - 1 }>,
             add: impl Fn($Ty<MIN, MAX, DEF>, $SigInt) -> $Ty<MIN, MAX, DEF>,
@@ -1097,6 +2443,26 @@ macro_rules! tests_int {
             assert_eq!(cnst.get(), MAX.checked_sub_unsigned(rhs.get()).unwrap());
         }
 
+        // What a bound error is expected to know about a result that may not be
+        // representable by the primitive, in which case the operation is kept.
+        fn rejected(value: Option<$SigInt>, op: Rejected) -> Rejected {
+            value.map_or(op, Rejected::Value)
+        }
+
+        // The operands of a mixed signedness operation, with `rhs` kept unsigned if
+        // it is not representable by the primitive.
+        fn unsigned_operands(
+            lhs: $SigInt,
+            rhs: $UnsInt,
+            op: fn($SigInt, $SigInt) -> Rejected,
+            wide: fn($SigInt, u128) -> Rejected,
+        ) -> Rejected {
+            match <$SigInt>::try_from(rhs) {
+                Ok(rhs) => op(lhs, rhs),
+                Err(_) => wide(lhs, rhs as u128),
+            }
+        }
+
         fn assert_unsigned_unbounded<
             const MIN: $SigInt,
             const MAX: $SigInt,
@@ -1329,7 +2695,10 @@ macro_rules! impl_int_prop_tests_for {
                 fn try_add_unbounded(rhs in SigRhsGen) {
                     assert_add_unbounded(
                         rhs,
-                        (Err(CnstErr::greater()), Err(CnstErr::lower())),
+                        (
+                            Err(CnstErr::Greater(MaxErr::with(Rejected::Add(Cnst::MAX, rhs.get())))),
+                            Err(CnstErr::Lower(MinErr::with(Rejected::Add(Cnst::MIN, -rhs)))),
+                        ),
                         Cnst::try_add
                     );
                 }
@@ -1352,7 +2721,10 @@ macro_rules! impl_int_prop_tests_for {
                 fn try_sub_unbounded(rhs in SigRhsGen) {
                     assert_sub_unbounded(
                         rhs,
-                        (Err(CnstErr::lower()), Err(CnstErr::greater())),
+                        (
+                            Err(CnstErr::Lower(MinErr::with(Rejected::Sub(Cnst::MIN, rhs.get())))),
+                            Err(CnstErr::Greater(MaxErr::with(Rejected::Sub(Cnst::MAX, -rhs)))),
+                        ),
                         Cnst::try_sub
                     );
                 }
@@ -1373,7 +2745,10 @@ macro_rules! impl_int_prop_tests_for {
 
                 #[test]
                 fn try_add_unsigned_unbounded((cnst, rhs) in (SigCnstGen, UnsRhsGen)) {
-                    assert_unsigned_unbounded(cnst, rhs, Err(MaxErr::new()), Cnst::try_add_unsigned);
+                    let (lhs, abs) = (cnst.get(), rhs.get());
+                    let op = unsigned_operands(lhs, abs, Rejected::Add, Rejected::AddUnsigned);
+                    let expected = Err(MaxErr::with(rejected(lhs.checked_add_unsigned(abs), op)));
+                    assert_unsigned_unbounded(cnst, rhs, expected, Cnst::try_add_unsigned);
                 }
 
                 #[test]
@@ -1388,7 +2763,10 @@ macro_rules! impl_int_prop_tests_for {
 
                 #[test]
                 fn try_sub_unsigned_unbounded((cnst, rhs) in (SigCnstGen, UnsRhsGen)) {
-                    assert_unsigned_unbounded(cnst, rhs, Err(MinErr::new()), Cnst::try_sub_unsigned);
+                    let (lhs, abs) = (cnst.get(), rhs.get());
+                    let op = unsigned_operands(lhs, abs, Rejected::Sub, Rejected::SubUnsigned);
+                    let expected = Err(MinErr::with(rejected(lhs.checked_sub_unsigned(abs), op)));
+                    assert_unsigned_unbounded(cnst, rhs, expected, Cnst::try_sub_unsigned);
                 }
 
                 #[test]
@@ -1479,6 +2857,16 @@ macro_rules! impl_int_prop_tests_for {
                         cnst
                     });
                 }
+
+                #[test]
+                fn mul_consistency((cnst, rhs) in (SigCnstGen, SigRhsGen)) {
+                    assert_mul_consistency::<{ Cnst::MIN }, { Cnst::MAX }, { Cnst::DEF }>(cnst, rhs);
+                }
+
+                #[test]
+                fn pow_consistency((cnst, exp) in (SigCnstGen, 0..u32::MAX)) {
+                    assert_pow_consistency::<{ Cnst::MIN }, { Cnst::MAX }, { Cnst::DEF }>(cnst, exp);
+                }
             }
         }
     )+};
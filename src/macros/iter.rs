@@ -0,0 +1,483 @@
+// Implements an iterator over the values of `Constrained` types' ranges.
+macro_rules! constrained_iter_impl {
+    (   $Int:ty, $UnsInt:ty, $md:ident, $Ty:ident, $Err:ident, $min:literal..=$max:literal
+    ) => {
+        #[doc = concat!("An iterator over the values of a [`", stringify!($Ty), "`]'s range, in ascending order.")]
+        ///
+        #[doc = concat!("This struct is created by the [`iter_all`], [`iter_from`] and [`iter_to`] methods on [`", stringify!($Ty), "`].")]
+        /// It implements `DoubleEndedIterator`, and also `ExactSizeIterator` for 8 and
+        /// 16 bit integers, where the number of values always fits in `usize`.
+        ///
+        #[doc = concat!("[`iter_all`]: ", stringify!($Ty), "::iter_all")]
+        #[doc = concat!("[`iter_from`]: ", stringify!($Ty), "::iter_from")]
+        #[doc = concat!("[`iter_to`]: ", stringify!($Ty), "::iter_to")]
+        #[derive(Clone, Debug)]
+        pub struct Iter<const MIN: $Int, const MAX: $Int, const DEF: $Int> {
+            front: $Int,
+            back: $Int,
+            // Like `RangeInclusive`, `front == back` is not enough to tell if the
+            // last value was already yielded.
+            exhausted: bool,
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> Iter<MIN, MAX, DEF> {
+            /// Creates an iterator over `front..=back`.
+            ///
+            /// Caller must ensure that both values are contained by the range, and
+            /// that `front` <= `back`.
+            #[must_use]
+            const fn new(front: $Int, back: $Int) -> Self {
+                debug_assert!(front <= back, "front can't be greater than back");
+                Self { front, back, exhausted: false }
+            }
+
+            /// Returns the number of values left to be yielded.
+            ///
+            /// Can't overflow since the range's size always fits in the unsigned integer.
+            #[must_use]
+            const fn remaining(&self) -> $UnsInt {
+                if self.exhausted {
+                    0
+                } else {
+                    self.back.abs_diff(self.front) + 1
+                }
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> Iterator for Iter<MIN, MAX, DEF> {
+            type Item = $Ty<MIN, MAX, DEF>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+                let value = self.front;
+                if value == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front += 1;
+                }
+                Some($Ty(value))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.remaining()) {
+                    Ok(remaining) => (remaining, Some(remaining)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> DoubleEndedIterator for Iter<MIN, MAX, DEF> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.exhausted {
+                    return None;
+                }
+                let value = self.back;
+                if value == self.front {
+                    self.exhausted = true;
+                } else {
+                    self.back -= 1;
+                }
+                Some($Ty(value))
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::core::iter::FusedIterator for Iter<MIN, MAX, DEF> {}
+
+        constrained_iter_exact_size_impl! { $Int, $md, $Ty }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::core::iter::Step for $Ty<MIN, MAX, DEF> {
+            #[inline]
+            fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+                if start.0 <= end.0 {
+                    usize::try_from(end.0.abs_diff(start.0)).ok()
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                // The result is contained by the range, so wrapping arithmetics on
+                // the primitive won't actually wrap.
+                match <$UnsInt>::try_from(count) {
+                    Ok(count) if count <= MAX.abs_diff(start.0) => {
+                        Some(Self(start.0.wrapping_add(count as $Int)))
+                    }
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                // The result is contained by the range, so wrapping arithmetics on
+                // the primitive won't actually wrap.
+                match <$UnsInt>::try_from(count) {
+                    Ok(count) if count <= start.0.abs_diff(MIN) => {
+                        Some(Self(start.0.wrapping_sub(count as $Int)))
+                    }
+                    _ => None,
+                }
+            }
+        }
+
+        constrained_iter_fold_impl! {
+            $Int, $Ty,
+            { Sum(sum) => checked_add, 0 },
+            { Product(product) => checked_mul, 1 },
+        }
+
+        // The `where guard` protects this type's constructors by only implementing them when
+        // concrete parameter values comply with the enforced conditions for construction.
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            /// Returns an iterator over all values contained by the range, in ascending
+            /// order.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut iter = Constrained::iter_all();
+            /// assert_eq!(iter.next(), Some(Constrained::new_min()));
+            /// assert_eq!(iter.next_back(), Some(Constrained::new_max()));
+            /// ```
+            ///
+            #[doc = concat!("Since [`", stringify!($Ty), "`] implements the `Step` trait, ranges of it can also be")]
+            /// iterated directly.
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<1, 5>;")]
+            ///
+            /// let (start, end) = (Constrained::new_min(), Constrained::new_max());
+            /// assert!((start..=end).map(|cnst| cnst.get()).eq([1, 2, 3, 4, 5]));
+            /// assert!((start..end).rev().map(|cnst| cnst.get()).eq([4, 3, 2, 1]));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn iter_all() -> Iter<MIN, MAX, DEF> {
+                Iter::new(MIN, MAX)
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Returns an iterator over the values contained by the range, starting
+            /// from this value up to the range's upper bound, in ascending order.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<1, 5>;")]
+            ///
+            /// let constrained = Constrained::new(3)?;
+            /// assert!(constrained.iter_from().map(|cnst| cnst.get()).eq([3, 4, 5]));
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<1, 5>>(())")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn iter_from(self) -> Iter<MIN, MAX, DEF> {
+                Iter::new(self.0, MAX)
+            }
+
+            /// Returns an iterator over the values contained by the range, starting
+            /// from the range's lower bound up to this value, in ascending order.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<1, 5>;")]
+            ///
+            /// let constrained = Constrained::new(3)?;
+            /// assert!(constrained.iter_to().map(|cnst| cnst.get()).eq([1, 2, 3]));
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err), "<1, 5>>(())")]
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn iter_to(self) -> Iter<MIN, MAX, DEF> {
+                Iter::new(MIN, self.0)
+            }
+        }
+    };
+}
+
+// Implements core::iter traits that fold an iterator of `Constrained` values into
+// an `Option`, returning `None` if any intermediate result is out of the range's
+// bounds. Empty iterators return the `$empty` value, if it is contained by the range.
+macro_rules! constrained_iter_fold_impl {
+    ($Int:ty, $Ty:ident, $({ $Trait:ident($trait_f:ident) => $checked_f:ident, $empty:literal }),+ $(,)?) => {$(
+        // Guard the constructor for empty iterators.
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            ::core::iter::$Trait<$Ty<MIN, MAX, DEF>> for Option<$Ty<MIN, MAX, DEF>>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            fn $trait_f<I: Iterator<Item = $Ty<MIN, MAX, DEF>>>(mut iter: I) -> Self {
+                match iter.next() {
+                    Some(first) => iter.try_fold(first, |acc, cnst| acc.$checked_f(cnst.get())),
+                    None => $Ty::checked_new($empty),
+                }
+            }
+        }
+
+        // Guard the constructor for empty iterators.
+        impl<'a, const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            ::core::iter::$Trait<&'a $Ty<MIN, MAX, DEF>> for Option<$Ty<MIN, MAX, DEF>>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            fn $trait_f<I: Iterator<Item = &'a $Ty<MIN, MAX, DEF>>>(iter: I) -> Self {
+                <Self as ::core::iter::$Trait<$Ty<MIN, MAX, DEF>>>::$trait_f(iter.copied())
+            }
+        }
+    )+};
+}
+
+// Implements `ExactSizeIterator` for iterators whose number of values always
+// fits in `usize`, like core does for `RangeInclusive`.
+macro_rules! constrained_iter_exact_size_impl {
+    ($Int:ty, u8, $Ty:ident) => {
+        constrained_iter_exact_size_impl! { @impl $Int, $Ty }
+    };
+    ($Int:ty, u16, $Ty:ident) => {
+        constrained_iter_exact_size_impl! { @impl $Int, $Ty }
+    };
+    ($Int:ty, i8, $Ty:ident) => {
+        constrained_iter_exact_size_impl! { @impl $Int, $Ty }
+    };
+    ($Int:ty, i16, $Ty:ident) => {
+        constrained_iter_exact_size_impl! { @impl $Int, $Ty }
+    };
+    ($Int:ty, $md:ident, $Ty:ident) => {};
+    (@impl $Int:ty, $Ty:ident) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ExactSizeIterator
+            for Iter<MIN, MAX, DEF>
+        {
+        }
+
+        #[cfg(test)]
+        mod tests_iter_exact_size {
+            use super::*;
+
+            #[test]
+            fn iter_len() {
+                type CnstTest = $Ty<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+                let mut iter = CnstTest::iter_all();
+                assert_eq!(iter.len(), usize::from(CnstTest::MAX.abs_diff(CnstTest::MIN)) + 1);
+                iter.next();
+                iter.next_back();
+                assert_eq!(iter.len(), usize::from(CnstTest::MAX.abs_diff(CnstTest::MIN)) - 1);
+                assert_eq!(iter.count(), usize::from(CnstTest::MAX.abs_diff(CnstTest::MIN)) - 1);
+            }
+        }
+    };
+}
+
+// Implements tests for iterators over `Constrained` types' ranges.
+#[cfg(test)]
+macro_rules! tests_iter {
+    ($Int:ty, $ty_path:path, $Ty:ident) => {
+        use $ty_path::*;
+
+        type CnstTest = $Ty<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+        type SmallTest = $Ty<1, 5>;
+
+        #[test]
+        fn iter_all_forward() {
+            let mut iter = SmallTest::iter_all();
+            for value in 1..=5 {
+                assert_eq!(iter.next().map(|cnst| cnst.get()), Some(value));
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn iter_all_backward() {
+            let mut iter = SmallTest::iter_all();
+            for value in (1..=5).rev() {
+                assert_eq!(iter.next_back().map(|cnst| cnst.get()), Some(value));
+            }
+            assert_eq!(iter.next_back(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn iter_all_meet_in_the_middle() {
+            let mut iter = SmallTest::iter_all();
+            assert_eq!(iter.next().map(|cnst| cnst.get()), Some(1));
+            assert_eq!(iter.next_back().map(|cnst| cnst.get()), Some(5));
+            assert_eq!(iter.next().map(|cnst| cnst.get()), Some(2));
+            assert_eq!(iter.next_back().map(|cnst| cnst.get()), Some(4));
+            assert_eq!(iter.next_back().map(|cnst| cnst.get()), Some(3));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn iter_all_bounds() {
+            let mut iter = CnstTest::iter_all();
+            assert_eq!(iter.next(), Some(CnstTest::new_min()));
+            assert_eq!(iter.next_back(), Some(CnstTest::new_max()));
+
+            type FullTest = $Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>;
+            let mut iter = FullTest::iter_all();
+            assert_eq!(iter.next_back(), Some(FullTest::new_max()));
+            assert_eq!(iter.next(), Some(FullTest::new_min()));
+        }
+
+        #[test]
+        fn iter_from_and_to() {
+            let cnst = SmallTest::new(3).unwrap();
+            assert!(cnst.iter_from().map(|cnst| cnst.get()).eq([3, 4, 5]));
+            assert!(cnst.iter_to().map(|cnst| cnst.get()).eq([1, 2, 3]));
+            assert!(cnst.iter_to().rev().map(|cnst| cnst.get()).eq([3, 2, 1]));
+
+            assert!(SmallTest::new_max().iter_from().eq([SmallTest::new_max()]));
+            assert!(SmallTest::new_min().iter_to().eq([SmallTest::new_min()]));
+
+            assert!(CnstTest::new_max().iter_from().eq([CnstTest::new_max()]));
+            assert!(CnstTest::new_min().iter_to().eq([CnstTest::new_min()]));
+        }
+
+        #[test]
+        fn step_ranges() {
+            let (min, max) = (SmallTest::new_min(), SmallTest::new_max());
+            assert!((min..=max).map(|cnst| cnst.get()).eq(1..=5));
+            assert!((min..max).map(|cnst| cnst.get()).eq(1..5));
+            assert!((min..=max).rev().map(|cnst| cnst.get()).eq((1..=5).rev()));
+            assert!((min..).take(4).map(|cnst| cnst.get()).eq(1..=4));
+            assert!((max..=min).next().is_none());
+            assert_eq!((min..=max).nth(2).map(|cnst| cnst.get()), Some(3));
+            assert_eq!((min..=max).step_by(2).last(), Some(max));
+
+            let (min, max) = (CnstTest::new_min(), CnstTest::new_max());
+            let mut range = min..=max;
+            assert_eq!(range.next(), Some(min));
+            assert_eq!(range.next_back(), Some(max));
+        }
+
+        #[test]
+        fn step_steps_between() {
+            use ::core::iter::Step;
+            let (min, max) = (SmallTest::new_min(), SmallTest::new_max());
+            assert_eq!(Step::steps_between(&min, &max), Some(4));
+            assert_eq!(Step::steps_between(&max, &max), Some(0));
+            assert_eq!(Step::steps_between(&max, &min), None);
+
+            let (min, max) = (CnstTest::new_min(), CnstTest::new_max());
+            let steps = usize::try_from(CnstTest::MAX.abs_diff(CnstTest::MIN)).ok();
+            assert_eq!(Step::steps_between(&min, &max), steps);
+        }
+
+        #[test]
+        fn step_forward_and_backward_checked() {
+            use ::core::iter::Step;
+            let (min, max) = (SmallTest::new_min(), SmallTest::new_max());
+            assert_eq!(Step::forward_checked(min, 4), Some(max));
+            assert_eq!(Step::forward_checked(min, 5), None);
+            assert_eq!(Step::forward_checked(max, 0), Some(max));
+            assert_eq!(Step::forward_checked(max, usize::MAX), None);
+            assert_eq!(Step::backward_checked(max, 4), Some(min));
+            assert_eq!(Step::backward_checked(max, 5), None);
+            assert_eq!(Step::backward_checked(min, 0), Some(min));
+            assert_eq!(Step::backward_checked(min, usize::MAX), None);
+
+            let (min, max) = (CnstTest::new_min(), CnstTest::new_max());
+            assert_eq!(Step::forward_checked(max, 1), None);
+            assert_eq!(Step::backward_checked(min, 1), None);
+            assert_eq!(
+                Step::forward_checked(min, 1).map(|cnst| cnst.get()),
+                Some(CnstTest::MIN + 1)
+            );
+            assert_eq!(
+                Step::backward_checked(max, 1).map(|cnst| cnst.get()),
+                Some(CnstTest::MAX - 1)
+            );
+
+            // Steps larger than the signed primitive's max value.
+            type FullTest = $Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>;
+            let (min, max) = (FullTest::new_min(), FullTest::new_max());
+            let steps = usize::try_from(FullTest::MAX.abs_diff(FullTest::MIN));
+            if let Ok(steps) = steps {
+                assert_eq!(Step::forward_checked(min, steps), Some(max));
+                assert_eq!(Step::backward_checked(max, steps), Some(min));
+            }
+        }
+
+        #[test]
+        fn option_sum() {
+            let sum: Option<SmallTest> = [SmallTest::new_min(), SmallTest::new_min()].iter().sum();
+            assert_eq!(sum.map(|cnst| cnst.get()), Some(2));
+
+            let sum: Option<SmallTest> = SmallTest::iter_all().sum();
+            assert_eq!(sum, None);
+
+            let sum: Option<SmallTest> = ::core::iter::empty::<SmallTest>().sum();
+            assert_eq!(sum, None);
+
+            let sum: Option<$Ty<0, 5>> = ::core::iter::empty::<$Ty<0, 5>>().sum();
+            assert_eq!(sum.map(|cnst| cnst.get()), Some(0));
+        }
+
+        #[test]
+        fn option_product() {
+            let product: Option<SmallTest> = SmallTest::iter_all().take(2).product();
+            assert_eq!(product.map(|cnst| cnst.get()), Some(2));
+
+            let product: Option<SmallTest> = SmallTest::iter_all().product();
+            assert_eq!(product, None);
+
+            let product: Option<SmallTest> = [SmallTest::new_max()].iter().product();
+            assert_eq!(product.map(|cnst| cnst.get()), Some(5));
+
+            let product: Option<SmallTest> = ::core::iter::empty::<&SmallTest>().product();
+            assert_eq!(product.map(|cnst| cnst.get()), Some(1));
+
+            // Intermediate results out of the range's bounds are not recovered.
+            let product: Option<$Ty<0, 5>> =
+                [5, 5, 0].into_iter().map(|value| $Ty::new(value).unwrap()).product();
+            assert_eq!(product, None);
+
+            let product: Option<$Ty<2, 5>> = ::core::iter::empty::<$Ty<2, 5>>().product();
+            assert_eq!(product, None);
+        }
+
+        #[test]
+        fn iter_size_hint() {
+            let mut iter = SmallTest::iter_all();
+            assert_eq!(iter.size_hint(), (5, Some(5)));
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            iter.by_ref().for_each(drop);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+
+            let iter = CnstTest::iter_all();
+            let size = (CnstTest::MAX.abs_diff(CnstTest::MIN) + 1) as u128;
+            match iter.size_hint() {
+                (lower, Some(upper)) => {
+                    assert_eq!(lower, upper);
+                    assert_eq!(lower as u128, size);
+                }
+                (lower, None) => {
+                    assert_eq!(lower, usize::MAX);
+                    assert!(size > usize::MAX as u128);
+                }
+            }
+        }
+    };
+}
@@ -3,11 +3,62 @@
 #[macro_use]
 mod common;
 
+// Import:
+// - `constrained_ops_impl!`.
+// - `constrained_neg_impl!`.
+//
+// Required:
+// - `forward_ref_binop!`.
+// - `forward_ref_op_assign!`.
+// - `forward_ref_unop!`.
+#[macro_use]
+mod ops;
+
+// Import:
+// - `constrained_assign_impl!`.
+#[macro_use]
+mod assign;
+
+// Import:
+// - `constrained_bits_impl!`.
+#[macro_use]
+mod bits;
+
+// Import:
+// - `constrained_bounded_impl!`.
+#[macro_use]
+mod bounded;
+
+// Import:
+// - `constrained_sample_impl!`.
+#[macro_use]
+mod sample;
+
+// Import:
+// - `constrained_iter_impl!`.
+//
+// Required:
+// - `constrained_iter_exact_size_impl!`.
+#[macro_use]
+mod iter;
+
+// Import:
+// - `constrained_parse_impl!`.
+#[macro_use]
+mod parse;
+
 // Import:
 // - `constrained_uint_def_impl!`.
 //
 // Required:
 // - `constrained_def_impl!`.
+// - `constrained_ops_impl!`.
+// - `constrained_assign_impl!`.
+// - `constrained_bits_impl!`.
+// - `constrained_bounded_impl!`.
+// - `constrained_sample_impl!`.
+// - `constrained_iter_impl!`.
+// - `constrained_parse_impl!`.
 #[macro_use]
 mod uint;
 
@@ -16,11 +67,28 @@ mod uint;
 //
 // Required:
 // - `constrained_def_impl!`.
+// - `constrained_ops_impl!`.
+// - `constrained_neg_impl!`.
+// - `constrained_assign_impl!`.
+// - `constrained_bits_impl!`.
+// - `constrained_bounded_impl!`.
+// - `constrained_sample_impl!`.
+// - `constrained_iter_impl!`.
+// - `constrained_parse_impl!`.
 #[macro_use]
 mod int;
 
+// Import:
+// - `constrained_conversion_impl!`.
+//
+// Required:
+// - `constrained_from_impl!`.
+#[macro_use]
+mod convert;
+
 // Import:
 // - `forward_ref_binop!`.
 // - `forward_ref_op_assign!`.
+// - `forward_ref_unop!`.
 #[macro_use]
 mod refops;
@@ -0,0 +1,249 @@
+// Implements core::ops traits for `Constrained` types.
+//
+// Like primitive integers, operations panic if the result is out of the range's
+// bounds and `debug_assertions` are enabled, or else wrap around the bounds. These
+// are the `debug_assertions` this crate is built with, `overflow-checks` of the
+// dependent crate have no effect. Division and remainder of the primitive's `MIN`
+// by `-1` always panic, like they do for primitive integers.
+macro_rules! constrained_ops_impl {
+    ($Int:ty, $Ty:ident, $Err:ident) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF> {
+            /// Fallible division backing the `Div` implementation.
+            ///
+            /// Panics if `rhs` is zero or on primitive overflow, like primitive integers.
+            #[inline]
+            fn try_div_op(self, rhs: $Int) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(self.0 / rhs)
+            }
+
+            /// Fallible remainder backing the `Rem` implementation.
+            ///
+            /// Panics if `rhs` is zero or on primitive overflow, like primitive integers.
+            #[inline]
+            fn try_rem_op(self, rhs: $Int) -> Result<Self, $Err<MIN, MAX>> {
+                Self::new_unguarded(self.0 % rhs)
+            }
+
+            /// Wrapping division backing the `Div` implementation.
+            ///
+            /// Panics if `rhs` is zero or on primitive overflow, like primitive integers.
+            #[inline]
+            fn wrap_div_op(self, rhs: $Int) -> Self {
+                Self::wrap(self.0 / rhs)
+            }
+
+            /// Wrapping remainder backing the `Rem` implementation.
+            ///
+            /// Panics if `rhs` is zero or on primitive overflow, like primitive integers.
+            #[inline]
+            fn wrap_rem_op(self, rhs: $Int) -> Self {
+                Self::wrap(self.0 % rhs)
+            }
+        }
+
+        constrained_binop_impl! {
+            { $Int, $Ty },
+            { Add(add), AddAssign(add_assign) => try_add, wrapping_add },
+            { Sub(sub), SubAssign(sub_assign) => try_sub, wrapping_sub },
+            { Mul(mul), MulAssign(mul_assign) => try_mul, wrapping_mul },
+            { Div(div), DivAssign(div_assign) => try_div_op, wrap_div_op },
+            { Rem(rem), RemAssign(rem_assign) => try_rem_op, wrap_rem_op },
+        }
+    };
+}
+
+// Implements core::ops binary and assign operators for `Constrained` types.
+macro_rules! constrained_binop_impl {
+    (  { $Int:ty, $Ty:ident },
+     $({ $Bop:ident($bop_f:ident), $Aop:ident($aop_f:ident) => $try_f:ident, $wrapping_f:ident }),+ $(,)?
+    ) => {$(
+        use ::core::ops::{$Bop, $Aop};
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop<$Int> for $Ty<MIN, MAX, DEF>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $bop_f(self, rhs: $Int) -> Self {
+                if cfg!(debug_assertions) {
+                    match self.$try_f(rhs) {
+                        Ok(value) => value,
+                        Err(err) => panic!("{err}"),
+                    }
+                } else {
+                    self.$wrapping_f(rhs)
+                }
+            }
+        }
+
+        forward_ref_binop! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<$Int>, $bop_f for $Ty<MIN, MAX, DEF>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop for $Ty<MIN, MAX, DEF>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $bop_f(self, rhs: Self) -> Self {
+                <Self as $Bop<$Int>>::$bop_f(self, rhs.0)
+            }
+        }
+
+        forward_ref_binop! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<$Ty<MIN, MAX, DEF>>, $bop_f for $Ty<MIN, MAX, DEF>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop<$Int> for $Ty<MIN, MAX, DEF>
+        {
+            #[inline]
+            #[track_caller]
+            fn $aop_f(&mut self, rhs: $Int) {
+                *self = <Self as $Bop<$Int>>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<$Int>, $aop_f for $Ty<MIN, MAX, DEF>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop for $Ty<MIN, MAX, DEF>
+        {
+            #[inline]
+            #[track_caller]
+            fn $aop_f(&mut self, rhs: Self) {
+                *self = <Self as $Bop>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<$Ty<MIN, MAX, DEF>>, $aop_f for $Ty<MIN, MAX, DEF>
+        }
+    )+};
+}
+
+// Implements core::ops::Neg for signed `Constrained` types, with the same
+// overflow policy as `constrained_ops_impl!`.
+macro_rules! constrained_neg_impl {
+    ($SigInt:ty, $Ty:ident) => {
+        use ::core::ops::Neg;
+
+        impl<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt> Neg
+            for $Ty<MIN, MAX, DEF>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self {
+                if cfg!(debug_assertions) {
+                    match self.try_neg() {
+                        Ok(value) => value,
+                        Err(err) => panic!("{err}"),
+                    }
+                } else {
+                    self.wrapping_neg()
+                }
+            }
+        }
+
+        forward_ref_unop! {
+            #[track_caller]
+            impl<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt>
+                Neg, neg for $Ty<MIN, MAX, DEF>
+        }
+    };
+}
+
+// Implements tests for core::ops implementations of `Constrained` types.
+#[cfg(test)]
+macro_rules! tests_ops {
+    ($Int:ty, $ty_path:path, $Ty:ident) => {
+        use ::core::ops::{Add, Div, Mul, Rem, Sub};
+        use $ty_path::*;
+
+        type CnstTest = $Ty<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+        #[test]
+        fn bounded() {
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+            let one = CnstTest::new(1).unwrap();
+
+            assert_eq!(min + 1 - 1, min);
+            assert_eq!(&max - &one + one, max);
+            assert_eq!(max * 1 / one, max);
+            assert_eq!(<CnstTest as Rem<$Int>>::rem(max, <$Int>::MAX), max);
+            assert_eq!(<&CnstTest as Add<&CnstTest>>::add(&min, &one), min.wrapping_add(1));
+            assert_eq!(<CnstTest as Sub<&$Int>>::sub(max, &1), max.wrapping_sub(1));
+            assert_eq!(<&CnstTest as Mul<$Int>>::mul(&one, 1), one);
+            assert_eq!(<CnstTest as Div<CnstTest>>::div(max, one), max);
+
+            let mut cnst = min;
+            cnst += 1;
+            cnst -= &one;
+            cnst *= one;
+            cnst /= &1;
+            assert_eq!(cnst, min);
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "value must be lower or equal to")]
+        fn add_unbounded() {
+            let _ = CnstTest::new_max() + 1;
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "value must be greater or equal to")]
+        fn sub_assign_unbounded() {
+            let mut cnst = CnstTest::new_min();
+            cnst -= 1;
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "value must be lower or equal to")]
+        fn mul_unbounded() {
+            let _ = &CnstTest::new_max() * &2;
+        }
+
+        #[test]
+        #[cfg(not(debug_assertions))]
+        fn unbounded() {
+            let min = CnstTest::new_min();
+            let max = CnstTest::new_max();
+
+            assert_eq!(max + 1, max.wrapping_add(1));
+            assert_eq!(min - 1, min.wrapping_sub(1));
+            assert_eq!(max * 2, max.wrapping_mul(2));
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to divide by zero")]
+        fn div_by_zero() {
+            let _ = CnstTest::new_max() / 0;
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+        fn rem_by_zero() {
+            let _ = CnstTest::new_max() % 0;
+        }
+    };
+}
@@ -0,0 +1,331 @@
+// Implements string parsing and its error type for `Constrained` types.
+macro_rules! constrained_parse_impl {
+    (   $Int:ty, $md:ident, $Ty:ident, $Err:ident, $MinErr:ident, $MaxErr:ident,
+        $ParseErr:ident, $min:literal..=$max:literal, ($l:literal, $h:literal)
+    ) => {
+        #[doc = concat!("An error which can be returned when parsing a [`", stringify!($Ty), "`].")]
+        ///
+        /// The [`Invalid`] variant indicates that the string is not a valid integer
+        /// representation.
+        ///
+        #[doc = concat!("The [`Lower`] variant indicates that the value is lower than [`", stringify!($Ty), "::MIN`].")]
+        ///
+        #[doc = concat!("The [`Greater`] variant indicates that the value is greater than [`", stringify!($Ty), "::MAX`].")]
+        ///
+        /// If this crate's `std` feature is enabled, this error implements the standard
+        /// library's `Error` trait.
+        ///
+        #[doc = concat!("[`Invalid`]: ", stringify!($ParseErr), "::Invalid")]
+        #[doc = concat!("[`Lower`]: ", stringify!($ParseErr), "::Lower")]
+        #[doc = concat!("[`Greater`]: ", stringify!($ParseErr), "::Greater")]
+        ///
+        /// # Example
+        ///
+        /// Variants can be pattern matched to identify why parsing failed.
+        /// ```
+        /// # #![allow(incomplete_features)]
+        /// # #![feature(generic_const_exprs)]
+        #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($ParseErr) , "};")]
+        ///
+        #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+        ///
+        /// // Invalid, below lower bound and above upper bound.
+        #[doc = concat!("let results = [\"1_0\".parse::<Constrained>(), \"", stringify!($l),
+            "\".parse(), \"", stringify!($h), "\".parse()];")]
+        /// for result in results {
+        ///     match result {
+        #[doc = concat!("        Err(", stringify!($ParseErr), "::Invalid(_)) => /*...*/ (),")]
+        #[doc = concat!("        Err(", stringify!($ParseErr), "::Lower(_)) => /*...*/ (),")]
+        #[doc = concat!("        Err(", stringify!($ParseErr), "::Greater(_)) => /*...*/ (),")]
+        ///         _ => unreachable!(),
+        ///     }
+        /// }
+        /// ```
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum $ParseErr<const MIN: $Int, const MAX: $Int> {
+            /// Indicates that the string is not a valid integer representation.
+            Invalid($crate::ParseIntError),
+
+            #[doc = concat!("Indicates that the parsed value is lower than [`", stringify!($Ty), "::MIN`].")]
+            Lower($MinErr<MIN>),
+
+            #[doc = concat!("Indicates that the parsed value is greater than [`", stringify!($Ty), "::MAX`].")]
+            Greater($MaxErr<MAX>),
+        }
+
+        impl<const MIN: $Int, const MAX: $Int> $ParseErr<MIN, MAX> {
+            /// Returns `Invalid` variant for an empty string.
+            #[must_use]
+            #[inline(always)]
+            const fn empty() -> Self {
+                Self::Invalid($crate::ParseIntError::empty())
+            }
+
+            /// Returns `Invalid` variant for a string with an invalid digit.
+            #[must_use]
+            #[inline(always)]
+            const fn invalid_digit() -> Self {
+                Self::Invalid($crate::ParseIntError::invalid_digit())
+            }
+
+            /// Converts a range bound violation.
+            #[must_use]
+            #[inline(always)]
+            const fn from_err(err: $Err<MIN, MAX>) -> Self {
+                match err {
+                    $Err::Lower(err) => Self::Lower(err),
+                    $Err::Greater(err) => Self::Greater(err),
+                }
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int> From<$Err<MIN, MAX>> for $ParseErr<MIN, MAX> {
+            fn from(err: $Err<MIN, MAX>) -> Self {
+                Self::from_err(err)
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int> ::core::fmt::Display for $ParseErr<MIN, MAX> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::Invalid(err) => err.fmt(f),
+                    Self::Lower(err) => err.fmt(f),
+                    Self::Greater(err) => err.fmt(f),
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<const MIN: $Int, const MAX: $Int> ::std::error::Error for $ParseErr<MIN, MAX> { }
+
+        // The `where guard` protects this type's constructors by only implementing them when
+        // concrete parameter values comply with the enforced conditions for construction.
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> $Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            /// Parses a string slice in a given base into a new instance, if the value
+            /// satisfies the range's inclusive bounds.
+            ///
+            /// The string is expected to be an optional `+` sign, or a `-` sign for
+            /// signed integers, followed by digits. Digits are a subset of these
+            /// characters, depending on `radix`: `0-9`, `a-z` and `A-Z`. Leading and
+            /// trailing whitespace represent an error.
+            ///
+            /// If the string is not a valid integer representation, the [`Invalid`]
+            /// variant is returned. If the value is out of the range's bounds, including
+            #[doc = concat!("values that overflow [`", stringify!($Int), "`], the variant of the violated bound is returned.")]
+            ///
+            #[doc = concat!("[`Invalid`]: ", stringify!($ParseErr), "::Invalid")]
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Ty), ", ", stringify!($ParseErr) , "};")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::from_str_radix("A", 16)?;
+            /// assert_eq!(constrained.get(), 10);
+            ///
+            /// // Out of inclusive bounds.
+            #[doc = concat!("assert!(Constrained::from_str_radix(\"", stringify!($h), "\", 10).is_err());")]
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($ParseErr),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, $ParseErr<MIN, MAX>> {
+                assert!(matches!(radix, 2..=36), "radix must lie in the range `[2, 36]`");
+
+                let src = src.as_bytes();
+                let (is_positive, digits) = match src {
+                    [] => return Err($ParseErr::empty()),
+                    [b'+' | b'-'] => return Err($ParseErr::invalid_digit()),
+                    [b'+', digits @ ..] => (true, digits),
+                    [b'-', digits @ ..] if <$Int>::MIN != 0 => (false, digits),
+                    _ => (true, src),
+                };
+
+                // Can't use `?` operator nor `for` loops on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                // https://github.com/rust-lang/rust/issues/87575.
+                let mut value: $Int = 0;
+                let mut idx = 0;
+                while idx < digits.len() {
+                    let digit = match $crate::parse::to_digit(digits[idx], radix) {
+                        Some(digit) => digit as $Int,
+                        None => return Err($ParseErr::invalid_digit()),
+                    };
+                    // Overflowing the primitive also means violating the range's bounds,
+                    // the remaining digits are not parsed so the value is unknown.
+                    let next = match value.checked_mul(radix as $Int) {
+                        Some(value) if is_positive => value.checked_add(digit),
+                        Some(value) => value.checked_sub(digit),
+                        None => None,
+                    };
+                    value = match next {
+                        Some(value) => value,
+                        None if is_positive => return Err($ParseErr::Greater($MaxErr::new())),
+                        None => return Err($ParseErr::Lower($MinErr::new())),
+                    };
+                    idx += 1;
+                }
+
+                match Self::new_unguarded(value) {
+                    Ok(this) => Ok(this),
+                    Err(err) => Err($ParseErr::from_err(err)),
+                }
+            }
+        }
+
+        // Guard this constructor.
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::core::str::FromStr for $Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            type Err = $ParseErr<MIN, MAX>;
+
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+    };
+}
+
+// Implements tests for string parsing.
+#[cfg(test)]
+macro_rules! tests_parse {
+    ($Int:ty, $ty_path:path, $Ty:ident, $ParseErr:ident) => {
+        use ::core::num::IntErrorKind;
+        use $ty_path::*;
+
+        #[cfg(feature = "std")]
+        type CnstTest = $Ty<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+        type SmallTest = $Ty<1, 100>;
+
+        fn invalid_kind<const MIN: $Int, const MAX: $Int>(
+            err: $ParseErr<MIN, MAX>,
+        ) -> IntErrorKind {
+            match err {
+                $ParseErr::Invalid(err) => err.kind().clone(),
+                err => panic!("expected invalid variant, found: {err:?}"),
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn parse_bounded() {
+            for value in [CnstTest::MIN, CnstTest::MAX, CnstTest::MIN / 2 + CnstTest::MAX / 2] {
+                let cnst: CnstTest = value.to_string().parse().unwrap();
+                assert_eq!(cnst.get(), value);
+            }
+
+            for value in SmallTest::range() {
+                let cnst: SmallTest = value.to_string().parse().unwrap();
+                assert_eq!(cnst.get(), value);
+
+                let cnst: SmallTest = format!("+{value:03}").parse().unwrap();
+                assert_eq!(cnst.get(), value);
+            }
+        }
+
+        #[test]
+        fn parse_unbounded() {
+            assert!(matches!("0".parse::<SmallTest>(), Err($ParseErr::Lower(_))));
+            assert!(matches!("101".parse::<SmallTest>(), Err($ParseErr::Greater(_))));
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn parse_primitive_bounds() {
+            let err = <$Int>::MIN.to_string().parse::<CnstTest>();
+            assert!(matches!(err, Err($ParseErr::Lower(_))));
+
+            let err = <$Int>::MAX.to_string().parse::<CnstTest>();
+            assert!(matches!(err, Err($ParseErr::Greater(_))));
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn parse_overflow() {
+            // One more digit than the primitive's bounds.
+            let err = format!("{}0", <$Int>::MAX).parse::<CnstTest>();
+            assert!(matches!(err, Err($ParseErr::Greater(_))));
+
+            if <$Int>::MIN != 0 {
+                let err = format!("{}0", <$Int>::MIN).parse::<CnstTest>();
+                assert!(matches!(err, Err($ParseErr::Lower(_))));
+            }
+        }
+
+        #[test]
+        fn parse_invalid() {
+            let kind = invalid_kind("".parse::<SmallTest>().unwrap_err());
+            assert_eq!(kind, IntErrorKind::Empty);
+
+            for src in ["+", "-", " 1", "1 ", "1_0", "++1", "0x1", "1.0", "a"] {
+                let kind = invalid_kind(src.parse::<SmallTest>().unwrap_err());
+                assert_eq!(kind, IntErrorKind::InvalidDigit, "src: {src:?}");
+            }
+
+            if <$Int>::MIN == 0 {
+                let kind = invalid_kind("-1".parse::<SmallTest>().unwrap_err());
+                assert_eq!(kind, IntErrorKind::InvalidDigit);
+            }
+        }
+
+        #[test]
+        fn from_str_radix() {
+            assert_eq!(SmallTest::from_str_radix("1100100", 2).unwrap().get(), 100);
+            assert_eq!(SmallTest::from_str_radix("144", 8).unwrap().get(), 100);
+            assert_eq!(SmallTest::from_str_radix("64", 16).unwrap().get(), 100);
+            assert_eq!(SmallTest::from_str_radix("2S", 36).unwrap().get(), 100);
+            assert_eq!(SmallTest::from_str_radix("2s", 36).unwrap().get(), 100);
+
+            let kind = invalid_kind(SmallTest::from_str_radix("2", 2).unwrap_err());
+            assert_eq!(kind, IntErrorKind::InvalidDigit);
+
+            assert!(matches!(SmallTest::from_str_radix("65", 16), Err($ParseErr::Greater(_))));
+        }
+
+        #[test]
+        fn from_str_radix_const() {
+            const CNST: Result<SmallTest, $ParseErr<1, 100>> = SmallTest::from_str_radix("z", 36);
+            assert_eq!(CNST.unwrap().get(), 35);
+        }
+
+        #[test]
+        #[should_panic(expected = "radix must lie in the range `[2, 36]`")]
+        fn from_str_radix_invalid_radix() {
+            let _ = SmallTest::from_str_radix("1", 37);
+        }
+
+        #[test]
+        fn from_err() {
+            let err: $ParseErr<1, 100> = SmallTest::new(0).unwrap_err().into();
+            assert!(matches!(err, $ParseErr::Lower(_)));
+
+            let err: $ParseErr<1, 100> = SmallTest::new(101).unwrap_err().into();
+            assert!(matches!(err, $ParseErr::Greater(_)));
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn parse_err_display() {
+            let err = "".parse::<SmallTest>().unwrap_err();
+            assert_eq!(err.to_string(), "cannot parse integer from empty string");
+
+            let err = "a".parse::<SmallTest>().unwrap_err();
+            assert_eq!(err.to_string(), "invalid digit found in string");
+
+            let err = "0".parse::<SmallTest>().unwrap_err();
+            assert_eq!(err.to_string(), "value must be greater or equal to 1, found 0");
+
+            let err = "101".parse::<SmallTest>().unwrap_err();
+            assert_eq!(err.to_string(), "value must be lower or equal to 100, found 101");
+        }
+    };
+}
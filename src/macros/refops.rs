@@ -35,11 +35,13 @@ macro_rules! forward_ref_binop {
     };
 
     // Non-const ops implementation.
-    (impl$(<$(const $c:ident: $i:ty),+>)? $imp:ident<$u:ty>, $method:ident for $t:ty) => {
+    // Attributes are forwarded to every method, e.g. `#[track_caller]`.
+    ($(#[$attr:meta])* impl$(<$(const $c:ident: $i:ty),+>)? $imp:ident<$u:ty>, $method:ident for $t:ty) => {
         impl$(<'a, $(const $c: $i,)+>)? $imp<$u> for &'a $t {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
+            $(#[$attr])*
             fn $method(self, rhs: $u) -> <$t as $imp<$u>>::Output {
                 $imp::$method(*self, rhs)
             }
@@ -49,6 +51,7 @@ macro_rules! forward_ref_binop {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
+            $(#[$attr])*
             fn $method(self, rhs: &$u) -> <$t as $imp<$u>>::Output {
                 $imp::$method(self, *rhs)
             }
@@ -58,6 +61,7 @@ macro_rules! forward_ref_binop {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
+            $(#[$attr])*
             fn $method(self, rhs: &$u) -> <$t as $imp<$u>>::Output {
                 $imp::$method(*self, *rhs)
             }
@@ -82,12 +86,46 @@ macro_rules! forward_ref_op_assign {
     };
 
     // Non-cont ops implementation.
-    (impl$(<$(const $c:ident: $i:ty),+>)? $imp:ident<$u:ty>, $method:ident for $t:ty) => {
+    // Attributes are forwarded to every method, e.g. `#[track_caller]`.
+    ($(#[$attr:meta])* impl$(<$(const $c:ident: $i:ty),+>)? $imp:ident<$u:ty>, $method:ident for $t:ty) => {
         impl$(<$(const $c: $i,)+>)? $imp<&$u> for $t {
             #[inline]
+            $(#[$attr])*
             fn $method(&mut self, rhs: &$u) {
                 $imp::$method(self, *rhs);
             }
         }
     };
 }
+
+// implements "&T op", based on "T op" where T is expected to be `Copy`able.
+// Requires `const_trait_impl` features.
+macro_rules! forward_ref_unop {
+    // Const ops implementation.
+    // This implementation is equivalent to the non-const version,
+    // but with the additional `const` keyword.
+    (impl$(<$(const $c:ident: $i:ty),+>)? const $imp:ident, $method:ident for $t:ty) => {
+        impl$(<'a, $(const $c: $i,)+>)? const $imp for &'a $t {
+            type Output = <$t as $imp>::Output;
+
+            #[inline]
+            fn $method(self) -> <$t as $imp>::Output {
+                $imp::$method(*self)
+            }
+        }
+    };
+
+    // Non-const ops implementation.
+    // Attributes are forwarded to every method, e.g. `#[track_caller]`.
+    ($(#[$attr:meta])* impl$(<$(const $c:ident: $i:ty),+>)? $imp:ident, $method:ident for $t:ty) => {
+        impl$(<'a, $(const $c: $i,)+>)? $imp for &'a $t {
+            type Output = <$t as $imp>::Output;
+
+            #[inline]
+            $(#[$attr])*
+            fn $method(self) -> <$t as $imp>::Output {
+                $imp::$method(*self)
+            }
+        }
+    };
+}
@@ -0,0 +1,66 @@
+// Implements `rand` sampling for `Constrained` types, uniformly over the whole
+// range with the `Standard` distribution, and between two values through
+// `SampleUniform`.
+macro_rules! constrained_sample_impl {
+    ($Int:ty, $Ty:ident) => {
+        #[cfg(feature = "rand")]
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            ::rand::distributions::Distribution<$Ty<MIN, MAX, DEF>>
+            for ::rand::distributions::Standard
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            #[inline]
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> $Ty<MIN, MAX, DEF> {
+                $Ty(::rand::Rng::gen_range(rng, MIN..=MAX))
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            ::rand::distributions::uniform::SampleUniform for $Ty<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            type Sampler = $crate::UniformConstrained<Self>;
+        }
+
+        #[cfg(feature = "rand")]
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            ::rand::distributions::uniform::UniformSampler
+            for $crate::UniformConstrained<$Ty<MIN, MAX, DEF>>
+        where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            type X = $Ty<MIN, MAX, DEF>;
+
+            #[inline]
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                use ::rand::distributions::uniform::{UniformInt, UniformSampler};
+                Self(<UniformInt<$Int> as UniformSampler>::new(low.borrow().0, high.borrow().0))
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                use ::rand::distributions::uniform::{UniformInt, UniformSampler};
+                Self(<UniformInt<$Int> as UniformSampler>::new_inclusive(
+                    low.borrow().0,
+                    high.borrow().0,
+                ))
+            }
+
+            #[inline]
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $Ty(::rand::distributions::uniform::UniformSampler::sample(&self.0, rng))
+            }
+        }
+    };
+}
@@ -77,6 +77,32 @@ macro_rules! constrained_uint_impl {
                 }
             }
 
+            /// Saturating substraction with a signed integer.
+            ///
+            /// Computes `self - rhs`, saturating the result at the range's lower bound
+            /// if the integer is positive, or at the range's upper bound if negative.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Saturates at upper bound.
+            /// constrained = constrained.saturating_sub_signed(-1);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_sub_signed(self, rhs: $SigInt) -> Self {
+                if rhs.is_negative() {
+                    self.saturating_add(rhs.unsigned_abs())
+                } else {
+                    self.saturating_sub(rhs.unsigned_abs())
+                }
+            }
+
             /// Checked integer addition. Computes `self + rhs`, returning [`None`] if
             /// result is greater than range's upper bound.
             ///
@@ -144,6 +170,28 @@ macro_rules! constrained_uint_impl {
                 }
             }
 
+            /// Checked substraction with a signed integer. Computes `self - rhs`,
+            /// returning [`None`] if result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_sub_signed(-1), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_sub_signed(self, rhs: $SigInt) -> Option<Self> {
+                if rhs.is_negative() {
+                    self.checked_add(rhs.unsigned_abs())
+                } else {
+                    self.checked_sub(rhs.unsigned_abs())
+                }
+            }
+
             /// Fallible integer addition. Computes `self + rhs`, returning an error if
             /// the result is greater than the range's upper bound.
             ///
@@ -164,7 +212,7 @@ macro_rules! constrained_uint_impl {
                 // Tracking issue: https://github.com/rust-lang/rust/issues/91930.
                 match self.checked_add(rhs) {
                     Some(this) => Ok(this),
-                    None => Err($MaxErr::<MAX>::new()),
+                    None => Err($MaxErr::<MAX>::with(Rejected::Add(self.0, rhs))),
                 }
             }
 
@@ -184,10 +232,12 @@ macro_rules! constrained_uint_impl {
             /// ```
             #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn try_add_signed(self, rhs: $SigInt) -> Result<Self, $Err<MIN, MAX>> {
-                match self.checked_add_signed(rhs) {
-                    Some(this) => Ok(this),
-                    None if rhs.is_positive() => Err($Err::greater()),
-                    None => Err($Err::lower()),
+                match self.0.checked_add_signed(rhs) {
+                    Some(value) => Self::new_unguarded(value),
+                    None if rhs.is_positive() => {
+                        Err($Err::Greater($MaxErr::with(Rejected::Add(self.0, rhs.unsigned_abs()))))
+                    }
+                    None => Err($Err::Lower($MinErr::with(Rejected::Sub(self.0, rhs.unsigned_abs())))),
                 }
             }
 
@@ -211,7 +261,38 @@ macro_rules! constrained_uint_impl {
                 // Tracking issue: https://github.com/rust-lang/rust/issues/91930.
                 match self.checked_sub(rhs) {
                     Some(this) => Ok(this),
-                    None => Err($MinErr::<MIN>::new()),
+                    None => Err($MinErr::<MIN>::with(Rejected::Sub(self.0, rhs))),
+                }
+            }
+
+            /// Fallible substraction with signed integer. Computes `self - rhs`,
+            /// returning an error if the result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert!(constrained.try_sub_signed(-1).is_err());
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_sub_signed(self, rhs: $SigInt) -> Result<Self, $Err<MIN, MAX>> {
+                let value = if rhs.is_negative() {
+                    self.0.checked_add(rhs.unsigned_abs())
+                } else {
+                    self.0.checked_sub(rhs.unsigned_abs())
+                };
+
+                match value {
+                    Some(value) => Self::new_unguarded(value),
+                    None if rhs.is_positive() => {
+                        Err($Err::Lower($MinErr::with(Rejected::Sub(self.0, rhs.unsigned_abs()))))
+                    }
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Add(self.0, rhs.unsigned_abs())))),
                 }
             }
 
@@ -230,7 +311,7 @@ macro_rules! constrained_uint_impl {
             /// constrained = constrained.wrapping_add(1);
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_add(mut self, mut rhs: $UnsInt) -> Self {
                 (self, rhs) = match self.0.overflowing_add(rhs) {
                     (value, false) if value <= MAX => return Self(value),
@@ -259,7 +340,7 @@ macro_rules! constrained_uint_impl {
             /// constrained = constrained.wrapping_add_signed(-1);
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_add_signed(self, rhs: $SigInt) -> Self {
                 match self.0.overflowing_add_signed(rhs) {
                     (value, false) if value >= MIN && value <= MAX => Self(value),
@@ -284,7 +365,7 @@ macro_rules! constrained_uint_impl {
             /// constrained = constrained.wrapping_sub(1);
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn wrapping_sub(mut self, mut rhs: $UnsInt) -> Self {
                 (self, rhs) = match self.0.overflowing_sub(rhs) {
                     (value, false) if value >= MIN => return Self(value),
@@ -295,6 +376,33 @@ macro_rules! constrained_uint_impl {
                 self.wrapping_sub(rhs)
             }
 
+            /// Wrapping (modular) substraction with signed integer.
+            ///
+            /// Computes `self - rhs`, wrapping the result around the range's lower
+            /// bound if the integer is positive, or at the range's upper bound if
+            /// negative.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Wraps around the upper bound.
+            /// constrained = constrained.wrapping_sub_signed(-1);
+            /// assert_eq!(constrained.get(), Constrained::MIN);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_sub_signed(self, rhs: $SigInt) -> Self {
+                if rhs.is_negative() {
+                    self.wrapping_add(rhs.unsigned_abs())
+                } else {
+                    self.wrapping_sub(rhs.unsigned_abs())
+                }
+            }
+
             /// Wrapping (modular) addition, indicating if result was wrapped around.
             ///
             /// Computes `self + rhs`, wrapping the result around the range's upper
@@ -316,7 +424,7 @@ macro_rules! constrained_uint_impl {
             /// assert_eq!(constrained.get(), Constrained::MIN);
             /// assert_eq!(wrapped, true);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_add(self, rhs: $UnsInt) -> (Self, bool) {
                 match self.0.overflowing_add(rhs) {
                     (value, false) if value <= MAX => (Self(value), false),
@@ -348,7 +456,7 @@ macro_rules! constrained_uint_impl {
             /// assert_eq!(constrained.get(), Constrained::MAX);
             /// assert_eq!(wrapped, true);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             pub const fn overflowing_add_signed(self, rhs: $SigInt) -> (Self, bool) {
                 match self.0.overflowing_add_signed(rhs) {
                     (value, false) if value >= MIN && value <= MAX => (Self(value), false),
@@ -358,11 +466,540 @@ macro_rules! constrained_uint_impl {
                 }
             }
 
-            /// Wrapping (modular) substraction, indicating if result was wrapped around.
-            ///
-            /// Computes `self - rhs`, wrapping the result around the range's lower
-            /// bound. If a wrapping substraction would have occurred, then the boolean
-            /// is set to `true`, else to `false`.
+            /// Wrapping (modular) substraction, indicating if result was wrapped around.
+            ///
+            /// Computes `self - rhs`, wrapping the result around the range's lower
+            /// bound. If a wrapping substraction would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_min();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the lower bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_sub(1);
+            /// assert_eq!(constrained.get(), Constrained::MAX);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_sub(self, rhs: $UnsInt) -> (Self, bool) {
+                match self.0.overflowing_sub(rhs) {
+                    (value, false) if value >= MIN => (Self(value), false),
+                    (value, false) => (Self::wrap_around_min(value), true),
+                    (wrapped, true) => (Self::overflowed_sub(wrapped), true)
+                }
+            }
+
+            /// Wrapping (modular) substraction with signed integer, indicating if result
+            /// was wrapped around.
+            ///
+            /// Computes `self - rhs`, wrapping the result around the range's lower
+            /// bound if the integer is positive, or at the range's upper bound if
+            /// negative. If a wrapping substraction would have occurred, then the
+            /// boolean is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the upper bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_sub_signed(-1);
+            /// assert_eq!(constrained.get(), Constrained::MIN);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_sub_signed(self, rhs: $SigInt) -> (Self, bool) {
+                if rhs.is_negative() {
+                    self.overflowing_add(rhs.unsigned_abs())
+                } else {
+                    self.overflowing_sub(rhs.unsigned_abs())
+                }
+            }
+
+            /// Saturating integer multiplication. Computes `self * rhs`, saturating the
+            /// result at the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Saturates at upper bound.
+            /// constrained = constrained.saturating_mul(2);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            ///
+            /// // Saturates at lower bound.
+            /// constrained = constrained.saturating_mul(0);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($min), ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_mul(self, rhs: $UnsInt) -> Self {
+                let rhs = self.0.saturating_mul(rhs);
+                Self::saturating_new_unguarded(rhs)
+            }
+
+            /// Checked integer multiplication. Computes `self * rhs`, returning [`None`]
+            /// if result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_mul(2), None);
+            /// // Below lower bound.
+            /// assert_eq!(constrained.checked_mul(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_mul(self, rhs: $UnsInt) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer multiplication. Computes `self * rhs`, returning an
+            /// error if the result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert!(constrained.try_mul(2).is_err());
+            /// // Below lower bound.
+            /// assert!(constrained.try_mul(0).is_err());
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_mul(self, rhs: $UnsInt) -> Result<Self, $Err<MIN, MAX>> {
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Self::new_unguarded(value),
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Mul(self.0, rhs)))),
+                }
+            }
+
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping the
+            /// result around the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `self * rhs` modulo the range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<0, 9>;")]
+            ///
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// // Wraps around the upper bound.
+            /// constrained = constrained.wrapping_mul(3);
+            /// assert_eq!(constrained.get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_mul(self, rhs: $UnsInt) -> Self {
+                match self.0.overflowing_mul(rhs) {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    (_, true) => Self::overflowed_mul(self.0, rhs),
+                }
+            }
+
+            /// Wrapping (modular) multiplication, indicating if result was wrapped around.
+            ///
+            /// Computes `self * rhs`, wrapping the result around the range's inclusive
+            /// bounds. If a wrapping multiplication would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<0, 9>;")]
+            ///
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the upper bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_mul(3);
+            /// assert_eq!(constrained.get(), 1);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_mul(self, rhs: $UnsInt) -> (Self, bool) {
+                match self.0.overflowing_mul(rhs) {
+                    (value, false) if value >= MIN && value <= MAX => (Self(value), false),
+                    (value, false) => (Self::wrap(value), true),
+                    (_, true) => (Self::overflowed_mul(self.0, rhs), true),
+                }
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating
+            /// the result at the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let mut constrained = Constrained::new_max();
+            /// // Saturates at upper bound.
+            /// constrained = constrained.saturating_pow(2);
+            #[doc = concat!("assert_eq!(constrained.get(), ", stringify!($max), ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_pow(self, exp: u32) -> Self {
+                let value = self.0.saturating_pow(exp);
+                Self::saturating_new_unguarded(value)
+            }
+
+            /// Checked integer exponentiation. Computes `self.pow(exp)`, returning
+            /// [`None`] if result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert_eq!(constrained.checked_pow(2), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_pow(exp) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer exponentiation. Computes `self.pow(exp)`, returning an
+            /// error if the result is out of the range's inclusive bounds.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            /// // Above upper bound.
+            /// assert!(constrained.try_pow(2).is_err());
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_pow(self, exp: u32) -> Result<Self, $Err<MIN, MAX>> {
+                match self.0.checked_pow(exp) {
+                    Some(value) => Self::new_unguarded(value),
+                    None => Err($Err::Greater($MaxErr::with(Rejected::Pow(self.0, exp)))),
+                }
+            }
+
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping
+            /// the result around the range's inclusive bounds.
+            ///
+            /// The returned value is the only value contained by the range that is
+            /// congruent to `self.pow(exp)` modulo the range's size.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<0, 9>;")]
+            ///
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// // Wraps around the upper bound.
+            /// constrained = constrained.wrapping_pow(2);
+            /// assert_eq!(constrained.get(), 9);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn wrapping_pow(self, exp: u32) -> Self {
+                match self.0.overflowing_pow(exp) {
+                    (value, false) if value >= MIN && value <= MAX => Self(value),
+                    (value, false) => Self::wrap(value),
+                    (_, true) => Self::overflowed_pow(self.0, exp),
+                }
+            }
+
+            /// Wrapping (modular) exponentiation, indicating if result was wrapped around.
+            ///
+            /// Computes `self.pow(exp)`, wrapping the result around the range's inclusive
+            /// bounds. If a wrapping exponentiation would have occurred, then the boolean
+            /// is set to `true`, else to `false`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<0, 9>;")]
+            ///
+            /// let mut constrained = Constrained::new(7).unwrap();
+            /// let mut wrapped: bool;
+            ///
+            /// // Wraps around the upper bound, the boolean is set to `true`.
+            /// (constrained, wrapped) = constrained.overflowing_pow(2);
+            /// assert_eq!(constrained.get(), 9);
+            /// assert_eq!(wrapped, true);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                match self.0.overflowing_pow(exp) {
+                    (value, false) if value >= MIN && value <= MAX => (Self(value), false),
+                    (value, false) => (Self::wrap(value), true),
+                    (_, true) => (Self::overflowed_pow(self.0, exp), true),
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating the result
+            /// at the range's lower bound, or returning [`None`] if `rhs == 0`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Saturates at lower bound.
+            /// assert_eq!(constrained.saturating_div(2), Some(constrained));
+            /// // Division by zero.
+            /// assert_eq!(constrained.saturating_div(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn saturating_div(self, rhs: $UnsInt) -> Option<Self> {
+                match self.0.checked_div(rhs) {
+                    Some(value) => Some(Self::saturating_new_unguarded(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked integer division. Computes `self / rhs`, returning [`None`] if
+            /// `rhs == 0` or the result is lower than the range's lower bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_div(2).unwrap().get(), ", stringify!($max), " / 2);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_div(0), None);
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Below lower bound.
+            /// assert_eq!(constrained.checked_div(2), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_div(self, rhs: $UnsInt) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_div(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning [`None`] if
+            /// `rhs == 0` or the result is lower than the range's lower bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_rem(100).unwrap().get(), ", stringify!($max), " % 100);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_rem(0), None);
+            /// // Below lower bound.
+            #[doc = concat!("assert_eq!(constrained.checked_rem(", stringify!($max), "), None);")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_rem(self, rhs: $UnsInt) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_rem(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked Euclidean division. Computes `self.div_euclid(rhs)`, returning
+            /// [`None`] if `rhs == 0` or the result is lower than the range's lower bound.
+            ///
+            /// Strictly equivalent to [`checked_div`] for unsigned integers.
+            ///
+            #[doc = concat!("[`checked_div`]: ", stringify!($Ty), "::checked_div")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_div_euclid(2).unwrap().get(), ", stringify!($max), " / 2);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_div_euclid(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_div_euclid(self, rhs: $UnsInt) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_div_euclid(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Checked Euclidean remainder. Computes `self.rem_euclid(rhs)`, returning
+            /// [`None`] if `rhs == 0` or the result is lower than the range's lower bound.
+            ///
+            /// Strictly equivalent to [`checked_rem`] for unsigned integers.
+            ///
+            #[doc = concat!("[`checked_rem`]: ", stringify!($Ty), "::checked_rem")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.checked_rem_euclid(100).unwrap().get(), ", stringify!($max), " % 100);")]
+            /// // Division by zero.
+            /// assert_eq!(constrained.checked_rem_euclid(0), None);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_rem_euclid(self, rhs: $UnsInt) -> Option<Self> {
+                // TODO: Can't use `?` operator on const fn yet:
+                // https://github.com/rust-lang/rust/issues/74935.
+                match self.0.checked_rem_euclid(rhs) {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Fallible integer division. Computes `self / rhs`, returning [`None`] if
+            /// `rhs == 0`, or an error if the result is lower than the range's lower
+            /// bound.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let constrained = Constrained::new_max();
+            #[doc = concat!("assert_eq!(constrained.try_div(2).unwrap()?.get(), ", stringify!($max), " / 2);")]
+            /// // Division by zero.
+            /// assert!(constrained.try_div(0).is_none());
+            ///
+            /// let constrained = Constrained::new_min();
+            /// // Below lower bound.
+            /// assert!(constrained.try_div(2).unwrap().is_err());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($MinErr),
+                "<", stringify!($min), ">>(())")]
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn try_div(self, rhs: $UnsInt) -> Option<Result<Self, $MinErr<MIN>>> {
+                // TODO: Can't use `ok_or` because it is not `const`.
+                // Tracking issue: https://github.com/rust-lang/rust/issues/91930.
+                match self.0.checked_div(rhs) {
+                    Some(value) => match Self::checked_new_unguarded(value) {
+                        Some(this) => Some(Ok(this)),
+                        None => Some(Err($MinErr::<MIN>::with(Rejected::Div(self.0, rhs)))),
+                    },
+                    None => None,
+                }
+            }
+
+            /// Computes the distance between `self` and `rhs`, which is their absolute
+            /// difference.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// let min = Constrained::new_min();
+            /// let max = Constrained::new_max();
+            ///
+            /// assert_eq!(min.distance(max), 253);
+            /// assert_eq!(max.distance(min), 253);
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn distance(self, rhs: Self) -> $UnsInt {
+                self.0.abs_diff(rhs.0)
+            }
+
+            /// Returns `true` if and only if `self == 2^k` for some `k`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("use constrained_int::", stringify!($md), "::", stringify!($Ty), ";")]
+            ///
+            #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
+            ///
+            /// assert!(Constrained::new(16)?.is_power_of_two());
+            /// assert!(!Constrained::new(10)?.is_power_of_two());
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
+            /// ```
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`. If
+            /// it is greater than the range's upper bound, [`None`] is returned.
             ///
             /// # Example
             ///
@@ -371,23 +1008,60 @@ macro_rules! constrained_uint_impl {
             ///
             #[doc = concat!("type Constrained = ", stringify!($Ty), "<", stringify!($min, $max), ">;")]
             ///
-            /// let mut constrained = Constrained::new_min();
-            /// let mut wrapped: bool;
+            /// let constrained = Constrained::new(10)?;
+            /// assert_eq!(constrained.checked_next_power_of_two().map(|c| c.get()), Some(16));
             ///
-            /// // Wraps around the lower bound, the boolean is set to `true`.
-            /// (constrained, wrapped) = constrained.overflowing_sub(1);
-            /// assert_eq!(constrained.get(), Constrained::MAX);
-            /// assert_eq!(wrapped, true);
+            /// // Above upper bound.
+            /// assert_eq!(Constrained::new_max().checked_next_power_of_two(), None);
+            #[doc = concat!("# Ok::<(), constrained_int::", stringify!($md), "::", stringify!($Err),
+                "<", stringify!($min, $max), ">>(())")]
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
-            pub const fn overflowing_sub(self, rhs: $UnsInt) -> (Self, bool) {
-                match self.0.overflowing_sub(rhs) {
-                    (value, false) if value >= MIN => (Self(value), false),
-                    (value, false) => (Self::wrap_around_min(value), true),
-                    (wrapped, true) => (Self::overflowed_sub(wrapped), true)
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                match self.0.checked_next_power_of_two() {
+                    Some(value) => Self::checked_new_unguarded(value),
+                    None => None,
+                }
+            }
+
+            /// Wrapping division backing the `Div` implementation of `Wrapping`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Wrapping`.
+            #[must_use]
+            pub(crate) const fn wrapping_div_op(self, rhs: $UnsInt) -> Self {
+                match self.0 / rhs {
+                    value if value >= MIN && value <= MAX => Self(value),
+                    value => Self::wrap(value),
+                }
+            }
+
+            /// Wrapping remainder backing the `Rem` implementation of `Wrapping`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Wrapping`.
+            #[must_use]
+            pub(crate) const fn wrapping_rem_op(self, rhs: $UnsInt) -> Self {
+                match self.0 % rhs {
+                    value if value >= MIN && value <= MAX => Self(value),
+                    value => Self::wrap(value),
                 }
             }
 
+            /// Saturating division backing the `Div` implementation of `Saturating`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Saturating`.
+            #[must_use]
+            pub(crate) const fn saturating_div_op(self, rhs: $UnsInt) -> Self {
+                Self::saturating_new_unguarded(self.0 / rhs)
+            }
+
+            /// Saturating remainder backing the `Rem` implementation of `Saturating`.
+            ///
+            /// Panics if `rhs` is zero, just like `core::num::Saturating`.
+            #[must_use]
+            pub(crate) const fn saturating_rem_op(self, rhs: $UnsInt) -> Self {
+                Self::saturating_new_unguarded(self.0 % rhs)
+            }
+
             /// Wraps the value around the range upper bound.
             ///
             /// Caller must ensure that `value` is greater than `MAX`, or else there will
@@ -482,6 +1156,107 @@ macro_rules! constrained_uint_impl {
                 }
             }
 
+            /// Handles overflowed `overflowing_mul` calls from inner integer.
+            ///
+            /// `lhs` and `rhs` are the operands of the overflowed multiplication.
+            #[must_use]
+            const fn overflowed_mul(lhs: $UnsInt, rhs: $UnsInt) -> Self {
+                let rem = Self::mul_remainders(Self::remainder(lhs), Self::remainder(rhs));
+                Self::from_remainder(rem)
+            }
+
+            /// Handles overflowed `overflowing_pow` calls from inner integer.
+            ///
+            /// `base` and `exp` are the operands of the overflowed exponentiation.
+            #[must_use]
+            const fn overflowed_pow(base: $UnsInt, exp: u32) -> Self {
+                let rem = Self::pow_remainders(Self::remainder(base), exp);
+                Self::from_remainder(rem)
+            }
+
+            /// Wraps any `value` into the range, returning the only value contained by
+            /// the range that is congruent to `value` modulo the range's size.
+            #[must_use]
+            const fn wrap(value: $UnsInt) -> Self {
+                Self::from_remainder(Self::remainder(value))
+            }
+
+            /// Returns `(lhs + rhs) / 2` rounded down, without overflowing.
+            #[must_use]
+            const fn half_sum(lhs: $UnsInt, rhs: $UnsInt) -> $UnsInt {
+                // Shifts round down both halves, and the lost bits only add up
+                // if they are both set.
+                (lhs >> 1) + (rhs >> 1) + (lhs & rhs & 1)
+            }
+
+            /// Returns the only value contained by the range that has `rem` as its
+            /// remainder by the range's size.
+            ///
+            /// Caller must ensure that `rem` is lower than `range_size()`, or else there
+            /// will be an unexpected overflow.
+            #[must_use]
+            const fn from_remainder(rem: $UnsInt) -> Self {
+                debug_assert!(rem < Self::range_size(), "rem must be lower than range size");
+                let min = Self::remainder(MIN);
+                // Can't overflow since the offset is always lower than `range_size()`.
+                let offset = if rem >= min {
+                    rem - min
+                } else {
+                    Self::range_size() - (min - rem)
+                };
+                Self(MIN + offset)
+            }
+
+            /// Computes `(lhs + rhs) % range_size()` without overflowing.
+            ///
+            /// Caller must ensure that both `lhs` and `rhs` are lower than `range_size()`,
+            /// or else there will be an unexpected overflow.
+            #[must_use]
+            const fn add_remainders(lhs: $UnsInt, rhs: $UnsInt) -> $UnsInt {
+                let complement = Self::range_size() - rhs;
+                if lhs >= complement {
+                    lhs - complement
+                } else {
+                    lhs + rhs
+                }
+            }
+
+            /// Computes `(lhs * rhs) % range_size()` without overflowing, by doubling
+            /// and adding remainders.
+            ///
+            /// Caller must ensure that both `lhs` and `rhs` are lower than `range_size()`,
+            /// or else there will be an unexpected overflow.
+            #[must_use]
+            const fn mul_remainders(mut lhs: $UnsInt, mut rhs: $UnsInt) -> $UnsInt {
+                let mut rem = 0;
+                while rhs > 0 {
+                    if rhs & 1 == 1 {
+                        rem = Self::add_remainders(rem, lhs);
+                    }
+                    lhs = Self::add_remainders(lhs, lhs);
+                    rhs >>= 1;
+                }
+                rem
+            }
+
+            /// Computes `base.pow(exp) % range_size()` without overflowing, by squaring
+            /// and multiplying remainders.
+            ///
+            /// Caller must ensure that `base` is lower than `range_size()`, or else there
+            /// will be an unexpected overflow.
+            #[must_use]
+            const fn pow_remainders(mut base: $UnsInt, mut exp: u32) -> $UnsInt {
+                let mut rem = Self::remainder(1);
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        rem = Self::mul_remainders(rem, base);
+                    }
+                    base = Self::mul_remainders(base, base);
+                    exp >>= 1;
+                }
+                rem
+            }
+
             /// Computes the remainder of `value` by the range's size.
             #[must_use]
             const fn remainder(value: $UnsInt) -> $UnsInt {
@@ -516,7 +1291,7 @@ macro_rules! constrained_uint_impl {
 // Defines mods, containers, errors, impls, tests and default doc values for unsigned integers.
 macro_rules! constrained_uint_def_impl {
     ($({ $UnsInt:ty, $SigInt:ty, $uint_md:ident, $sint_md:ident,
-         $Ty:ident, $Err:ident, $MinErr:ident, $MaxErr:ident }),+ $(,)*
+         $Ty:ident, $Err:ident, $MinErr:ident, $MaxErr:ident, $ParseErr:ident }),+ $(,)*
     ) => {$(
         #[doc = concat!("Container and Error types for a range constrained [`prim@", stringify!($UnsInt), "`].")]
         pub mod $uint_md {
@@ -528,6 +1303,46 @@ macro_rules! constrained_uint_def_impl {
                 $UnsInt, $SigInt, $uint_md, $Ty, $Err, $MinErr, $MaxErr, 1..=254
             }
 
+            constrained_ops_impl! {
+                $UnsInt, $Ty, $Err
+            }
+
+            constrained_bits_impl! {
+                $UnsInt, $uint_md, $Ty, $Err
+            }
+
+            constrained_assign_impl! {
+                $UnsInt, $uint_md, $Ty, $Err, 1..=254,
+                { $UnsInt, new_max, 1, saturating_add_assign => saturating_add,
+                  wrapping_add_assign => wrapping_add, checked_add_assign => try_add },
+                { $SigInt, new_max, 1, saturating_add_signed_assign => saturating_add_signed,
+                  wrapping_add_signed_assign => wrapping_add_signed, checked_add_signed_assign => try_add_signed },
+                { $UnsInt, new_min, 1, saturating_sub_assign => saturating_sub,
+                  wrapping_sub_assign => wrapping_sub, checked_sub_assign => try_sub },
+                { $SigInt, new_min, 1, saturating_sub_signed_assign => saturating_sub_signed,
+                  wrapping_sub_signed_assign => wrapping_sub_signed, checked_sub_signed_assign => try_sub_signed },
+                { $UnsInt, new_max, 2, saturating_mul_assign => saturating_mul,
+                  wrapping_mul_assign => wrapping_mul, checked_mul_assign => try_mul },
+                { u32, new_max, 2, saturating_pow_assign => saturating_pow,
+                  wrapping_pow_assign => wrapping_pow, checked_pow_assign => try_pow },
+            }
+
+            constrained_bounded_impl! {
+                $UnsInt, $Ty, $Err
+            }
+
+            constrained_sample_impl! {
+                $UnsInt, $Ty
+            }
+
+            constrained_iter_impl! {
+                $UnsInt, $UnsInt, $uint_md, $Ty, $Err, 1..=254
+            }
+
+            constrained_parse_impl! {
+                $UnsInt, $uint_md, $Ty, $Err, $MinErr, $MaxErr, $ParseErr, 1..=254, (0, 255)
+            }
+
             #[cfg(test)]
             mod tests_uint_common {
                 tests_common! {
@@ -535,6 +1350,60 @@ macro_rules! constrained_uint_def_impl {
                 }
             }
 
+            #[cfg(test)]
+            mod tests_uint_ops {
+                tests_ops! {
+                    $UnsInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_uint_bits {
+                tests_bits! {
+                    $UnsInt, super, $Ty, $Err
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_uint_bounded {
+                tests_bounded! {
+                    $UnsInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_uint_assign {
+                tests_assign! {
+                    super, $Ty,
+                    { $UnsInt, 0..=12, saturating_add_assign => saturating_add,
+                      wrapping_add_assign => wrapping_add, checked_add_assign => try_add },
+                    { $SigInt, -12..=12, saturating_add_signed_assign => saturating_add_signed,
+                      wrapping_add_signed_assign => wrapping_add_signed, checked_add_signed_assign => try_add_signed },
+                    { $UnsInt, 0..=12, saturating_sub_assign => saturating_sub,
+                      wrapping_sub_assign => wrapping_sub, checked_sub_assign => try_sub },
+                    { $SigInt, -12..=12, saturating_sub_signed_assign => saturating_sub_signed,
+                      wrapping_sub_signed_assign => wrapping_sub_signed, checked_sub_signed_assign => try_sub_signed },
+                    { $UnsInt, 0..=12, saturating_mul_assign => saturating_mul,
+                      wrapping_mul_assign => wrapping_mul, checked_mul_assign => try_mul },
+                    { u32, 0..=12, saturating_pow_assign => saturating_pow,
+                      wrapping_pow_assign => wrapping_pow, checked_pow_assign => try_pow },
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_uint_iter {
+                tests_iter! {
+                    $UnsInt, super, $Ty
+                }
+            }
+
+            #[cfg(test)]
+            mod tests_uint_parse {
+                tests_parse! {
+                    $UnsInt, super, $Ty, $ParseErr
+                }
+            }
+
             #[cfg(test)]
             mod tests_uint_specific {
                 tests_uint! {
@@ -554,7 +1423,213 @@ macro_rules! tests_uint {
         use crate::proptest::$sint_md::{SigRhs, SigRhsGen};
         use crate::proptest::$uint_md::{UnsCnstGen, UnsRhs, UnsRhsGen};
         use ::core::fmt::Debug;
-        use $ty_path::{guard_construction, $Err, $MaxErr, $MinErr, $Ty};
+        use $ty_path::{guard_construction, $Err, $MaxErr, $MinErr, $Ty, Rejected};
+
+        #[test]
+        fn next_power_of_two() {
+            type Cnst = $Ty<3, 100>;
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                let next = value.next_power_of_two();
+                assert_eq!(cnst.is_power_of_two(), value.is_power_of_two());
+                assert_eq!(
+                    cnst.checked_next_power_of_two().map(|cnst| cnst.get()),
+                    Cnst::new(next).ok().map(|cnst| cnst.get())
+                );
+            }
+
+            type FullTest = $Ty<0, { <$UnsInt>::MAX - 1 }>;
+            assert_eq!(FullTest::new_max().checked_next_power_of_two(), None);
+            assert_eq!(
+                FullTest::new_min().checked_next_power_of_two().map(|cnst| cnst.get()),
+                Some(1)
+            );
+        }
+
+        #[test]
+        fn mul_small_range() {
+            type Cnst = $Ty<3, 12>;
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for rhs in 0..=20 {
+                    let product = value * rhs;
+                    let mut expected = product;
+                    while expected > Cnst::MAX {
+                        expected -= Cnst::range_size();
+                    }
+                    while expected < Cnst::MIN {
+                        expected += Cnst::range_size();
+                    }
+                    let in_range = Cnst::range().contains(&product);
+
+                    assert_eq!(cnst.wrapping_mul(rhs).get(), expected);
+                    assert_eq!(cnst.overflowing_mul(rhs), ($Ty(expected), !in_range));
+                    assert_eq!(cnst.checked_mul(rhs).is_some(), in_range);
+                    assert_eq!(cnst.saturating_mul(rhs), Cnst::saturating_new(product));
+                    match cnst.try_mul(rhs) {
+                        Ok(cnst) => assert_eq!(cnst.get(), product),
+                        Err(err) if product < Cnst::MIN => {
+                            assert_eq!(err, $Err::lower_value(product))
+                        }
+                        Err(err) => assert_eq!(err, $Err::greater_value(product)),
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn pow_small_range() {
+            type Cnst = $Ty<3, 12>;
+            let (min, max) = (Cnst::MIN as i128, Cnst::MAX as i128);
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for exp in 0..=6 {
+                    let power = (value as i128).pow(exp);
+                    let expected = (power - min).rem_euclid(max - min + 1) + min;
+                    let in_range = (min..=max).contains(&power);
+
+                    assert_eq!(cnst.wrapping_pow(exp).get() as i128, expected);
+                    assert_eq!(cnst.overflowing_pow(exp), (cnst.wrapping_pow(exp), !in_range));
+                    assert_eq!(cnst.checked_pow(exp).is_some(), in_range);
+                    assert_eq!(cnst.saturating_pow(exp).get() as i128, power.clamp(min, max));
+                    match cnst.try_pow(exp) {
+                        Ok(cnst) => assert_eq!(cnst.get() as i128, power),
+                        Err(err) => {
+                            assert_eq!(matches!(err, $Err::Lower(_)), power < min);
+                            assert_eq!(err.value(), <$UnsInt>::try_from(power).ok());
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn div_small_range() {
+            type Cnst = $Ty<3, 12>;
+
+            for value in Cnst::range() {
+                let cnst = Cnst::new(value).unwrap();
+                for rhs in 0..=20 {
+                    let filter =
+                        |value: Option<$UnsInt>| value.filter(|v| Cnst::range().contains(v));
+                    let get = |cnst: Option<Cnst>| cnst.map(|cnst| cnst.get());
+
+                    assert_eq!(get(cnst.checked_div(rhs)), filter(value.checked_div(rhs)));
+                    assert_eq!(get(cnst.checked_rem(rhs)), filter(value.checked_rem(rhs)));
+                    assert_eq!(
+                        get(cnst.checked_div_euclid(rhs)),
+                        filter(value.checked_div_euclid(rhs))
+                    );
+                    assert_eq!(
+                        get(cnst.checked_rem_euclid(rhs)),
+                        filter(value.checked_rem_euclid(rhs))
+                    );
+
+                    if rhs == 0 {
+                        assert_eq!(cnst.saturating_div(rhs), None);
+                        assert_eq!(cnst.try_div(rhs), None);
+                    } else {
+                        let quotient = value / rhs;
+                        let remainder = value % rhs;
+                        let wrap = |mut value| {
+                            while value > Cnst::MAX {
+                                value -= Cnst::range_size();
+                            }
+                            while value < Cnst::MIN {
+                                value += Cnst::range_size();
+                            }
+                            value
+                        };
+
+                        assert_eq!(cnst.wrapping_div_op(rhs).get(), wrap(quotient));
+                        assert_eq!(cnst.wrapping_rem_op(rhs).get(), wrap(remainder));
+                        assert_eq!(cnst.saturating_div_op(rhs), Cnst::saturating_new(quotient));
+                        assert_eq!(cnst.saturating_rem_op(rhs), Cnst::saturating_new(remainder));
+                        assert_eq!(cnst.saturating_div(rhs), Some(Cnst::saturating_new(quotient)));
+                        match cnst.try_div(rhs).unwrap() {
+                            Ok(cnst) => assert_eq!(cnst.get(), quotient),
+                            Err(err) => {
+                                assert!(quotient < Cnst::MIN);
+                                assert_eq!(err.value(), Some(quotient));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn div_op_by_zero() {
+            let _ = $Ty::<3, 12>::new_max().wrapping_div_op(0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn rem_op_by_zero() {
+            let _ = $Ty::<3, 12>::new_max().saturating_rem_op(0);
+        }
+
+        fn assert_mul_consistency<const MIN: $UnsInt, const MAX: $UnsInt, const DEF: $UnsInt>(
+            cnst: $Ty<MIN, MAX, DEF>,
+            rhs: UnsRhs<{ 0 }, { <$UnsInt>::MAX - 1 }>,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let (wrapped, overflowed) = cnst.overflowing_mul(rhs.get());
+            assert_eq!(wrapped, cnst.wrapping_mul(rhs.get()));
+
+            match cnst.checked_mul(rhs.get()) {
+                Some(product) => {
+                    assert!(!overflowed, "expected `overflowing_mul` to not overflow");
+                    assert_eq!(product, wrapped);
+                    assert_eq!(cnst.try_mul(rhs.get()), Ok(product));
+                    assert_eq!(cnst.saturating_mul(rhs.get()), product);
+                }
+                None => {
+                    assert!(overflowed, "expected `overflowing_mul` to overflow");
+                    assert!(cnst.try_mul(rhs.get()).is_err());
+                    let saturated = cnst.saturating_mul(rhs.get());
+                    assert!(saturated == $Ty::new_min() || saturated == $Ty::new_max());
+                }
+            }
+
+            // Wrapping multiplication distributes over wrapping addition.
+            let next = cnst.wrapping_mul(rhs.get() + 1);
+            assert_eq!(next, wrapped.wrapping_add(cnst.get()));
+        }
+
+        fn assert_pow_consistency<const MIN: $UnsInt, const MAX: $UnsInt, const DEF: $UnsInt>(
+            cnst: $Ty<MIN, MAX, DEF>,
+            exp: u32,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let (wrapped, overflowed) = cnst.overflowing_pow(exp);
+            assert_eq!(wrapped, cnst.wrapping_pow(exp));
+
+            match cnst.checked_pow(exp) {
+                Some(power) => {
+                    assert!(!overflowed, "expected `overflowing_pow` to not overflow");
+                    assert_eq!(power, wrapped);
+                    assert_eq!(cnst.try_pow(exp), Ok(power));
+                    assert_eq!(cnst.saturating_pow(exp), power);
+                }
+                None => {
+                    assert!(overflowed, "expected `overflowing_pow` to overflow");
+                    assert!(cnst.try_pow(exp).is_err());
+                    let saturated = cnst.saturating_pow(exp);
+                    assert!(saturated == $Ty::new_min() || saturated == $Ty::new_max());
+                }
+            }
+
+            // Wrapping exponentiation is repeated wrapping multiplication.
+            let next = cnst.wrapping_pow(exp + 1);
+            assert_eq!(next, wrapped.wrapping_mul(cnst.get()));
+        }
 
         fn assert_add_bounded<const MIN: $UnsInt, const MAX: $UnsInt, const DEF: $UnsInt>(
             rhs: UnsRhs<{ 0 }, { $Ty::<MIN, MAX, DEF>::range_size() - 1 }>,
@@ -578,6 +1653,12 @@ macro_rules! tests_uint {
             assert_eq!(cnst.get(), MAX - rhs);
         }
 
+        // What a bound error is expected to know about a result that may not be
+        // representable by the primitive, in which case the operation is kept.
+        fn rejected(value: Option<$UnsInt>, op: Rejected) -> Rejected {
+            value.map_or(op, Rejected::Value)
+        }
+
         fn assert_unbounded<
             const MIN: $UnsInt,
             const MAX: $UnsInt,
@@ -691,6 +1772,61 @@ macro_rules! tests_uint {
             assert_eq!(cnst.get(), MAX - (rhs.unsigned() - 1));
         }
 
+        fn assert_sub_signed_bounded<const MIN: $UnsInt, const MAX: $UnsInt, const DEF: $UnsInt>(
+            rhs: SigRhs<{ 0 }, { $Ty::<MIN, MAX, DEF>::range_size_signed() - 1 }>,
+            sub: impl Fn($Ty<MIN, MAX, DEF>, $SigInt) -> $Ty<MIN, MAX, DEF>,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let mut cnst = $Ty::new_max();
+            cnst = sub(cnst, rhs.get());
+            assert_eq!(cnst.get(), MAX - rhs.unsigned());
+
+            let mut cnst = $Ty::new_min();
+            cnst = sub(cnst, -rhs);
+            assert_eq!(cnst.get(), MIN + rhs.unsigned());
+        }
+
+        fn assert_sub_signed_unbounded<
+            const MIN: $UnsInt,
+            const MAX: $UnsInt,
+            const DEF: $UnsInt,
+            T: Eq + Debug,
+        >(
+            rhs: SigRhs<{ 1 }, { <$SigInt>::MAX }>,
+            expected: (T, T),
+            op: impl Fn($Ty<MIN, MAX, DEF>, $SigInt) -> T,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let cnst = $Ty::new_min();
+            let returned = op(cnst, rhs.get());
+            assert_eq!(returned, expected.0);
+
+            let cnst = $Ty::new_max();
+            let returned = op(cnst, -rhs);
+            assert_eq!(returned, expected.1);
+        }
+
+        fn assert_wrapping_sub_signed_unbounded<
+            const MIN: $UnsInt,
+            const MAX: $UnsInt,
+            const DEF: $UnsInt,
+        >(
+            rhs: SigRhs<{ 1 }, { $Ty::<MIN, MAX, DEF>::range_size_signed() }>,
+            sub: impl Fn($Ty<MIN, MAX, DEF>, $SigInt) -> $Ty<MIN, MAX, DEF>,
+        ) where
+            $crate::Constraints<{ guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            let mut cnst = $Ty::new_min();
+            cnst = sub(cnst, rhs.get());
+            assert_eq!(cnst.get(), MAX - (rhs.unsigned() - 1));
+
+            let mut cnst = $Ty::new_max();
+            cnst = sub(cnst, -rhs);
+            assert_eq!(cnst.get(), MIN + (rhs.unsigned() - 1));
+        }
+
         // For testing purposes:
         //
         // Verify arithmetics for a range definition with the same lower bound as the
@@ -790,6 +1926,39 @@ macro_rules! impl_uint_prop_tests_for {
                     });
                 }
 
+                #[test]
+                fn checked_sub_signed_bounded(rhs in SigRhsGen) {
+                    assert_sub_signed_bounded(rhs, |cnst: Cnst, rhs| {
+                        cnst.checked_sub_signed(rhs).expect("expected `checked_sub_signed` to succeed")
+                    })
+                }
+
+                #[test]
+                fn try_sub_signed_bounded(rhs in SigRhsGen) {
+                    assert_sub_signed_bounded(rhs, |cnst: Cnst, rhs| {
+                        cnst.try_sub_signed(rhs).expect("expected `try_sub_signed` to succeed")
+                    })
+                }
+
+                #[test]
+                fn saturating_sub_signed_bounded(rhs in SigRhsGen) {
+                    assert_sub_signed_bounded(rhs, Cnst::saturating_sub_signed);
+                }
+
+                #[test]
+                fn wrapping_sub_signed_bounded(rhs in SigRhsGen) {
+                    assert_sub_signed_bounded(rhs, Cnst::wrapping_sub_signed);
+                }
+
+                #[test]
+                fn overflowing_sub_signed_bounded(rhs in SigRhsGen) {
+                    assert_sub_signed_bounded(rhs, |cnst: Cnst, rhs| {
+                        let (cnst, overflowed) = cnst.overflowing_sub_signed(rhs);
+                        assert!(!overflowed, "expected `overflowing_sub_signed` to not overflow");
+                        cnst
+                    });
+                }
+
                 #[test]
                 fn checked_sub_bounded(rhs in UnsRhsGen) {
                     assert_sub_bounded(rhs, |cnst: Cnst, rhs| {
@@ -830,7 +1999,8 @@ macro_rules! impl_uint_prop_tests_for {
 
                 #[test]
                 fn try_add_unbounded((cnst, rhs) in (UnsCnstGen, UnsRhsGen)) {
-                    assert_unbounded(cnst, rhs, Err(MaxErr::new()), Cnst::try_add);
+                    let expected = Err(MaxErr::with(Rejected::Add(cnst.get(), rhs.get())));
+                    assert_unbounded(cnst, rhs, expected, Cnst::try_add);
                 }
 
                 #[test]
@@ -845,7 +2015,8 @@ macro_rules! impl_uint_prop_tests_for {
 
                 #[test]
                 fn try_sub_unbounded((cnst, rhs) in (UnsCnstGen, UnsRhsGen)) {
-                    assert_unbounded(cnst, rhs, Err(MinErr::new()), Cnst::try_sub);
+                    let expected = Err(MinErr::with(Rejected::Sub(cnst.get(), rhs.get())));
+                    assert_unbounded(cnst, rhs, expected, Cnst::try_sub);
                 }
 
                 #[test]
@@ -860,9 +2031,15 @@ macro_rules! impl_uint_prop_tests_for {
 
                 #[test]
                 fn try_add_signed_unbounded(rhs in SigRhsGen) {
+                    let abs = rhs.get().unsigned_abs();
+                    let greater = rejected(Cnst::MAX.checked_add(abs), Rejected::Add(Cnst::MAX, abs));
+                    let lower = rejected(Cnst::MIN.checked_sub(abs), Rejected::Sub(Cnst::MIN, abs));
                     assert_add_signed_unbounded(
                         rhs,
-                        (Err(CnstErr::greater()), Err(CnstErr::lower())),
+                        (
+                            Err(CnstErr::Greater(MaxErr::with(greater))),
+                            Err(CnstErr::Lower(MinErr::with(lower))),
+                        ),
                         Cnst::try_add_signed
                     );
                 }
@@ -876,6 +2053,35 @@ macro_rules! impl_uint_prop_tests_for {
                     );
                 }
 
+                #[test]
+                fn checked_sub_signed_unbounded(rhs in SigRhsGen) {
+                    assert_sub_signed_unbounded(rhs, (None, None), Cnst::checked_sub_signed);
+                }
+
+                #[test]
+                fn try_sub_signed_unbounded(rhs in SigRhsGen) {
+                    let abs = rhs.get().unsigned_abs();
+                    let lower = rejected(Cnst::MIN.checked_sub(abs), Rejected::Sub(Cnst::MIN, abs));
+                    let greater = rejected(Cnst::MAX.checked_add(abs), Rejected::Add(Cnst::MAX, abs));
+                    assert_sub_signed_unbounded(
+                        rhs,
+                        (
+                            Err(CnstErr::Lower(MinErr::with(lower))),
+                            Err(CnstErr::Greater(MaxErr::with(greater))),
+                        ),
+                        Cnst::try_sub_signed
+                    );
+                }
+
+                #[test]
+                fn saturating_sub_signed_unbounded(rhs in SigRhsGen) {
+                    assert_sub_signed_unbounded(
+                        rhs,
+                        (Cnst::new_min(), Cnst::new_max()),
+                        Cnst::saturating_sub_signed
+                    );
+                }
+
                 #[test]
                 fn wrapping_add_unbounded(rhs in UnsRhsGen) {
                     assert_wrapping_add_unbounded(rhs, Cnst::wrapping_add);
@@ -904,6 +2110,20 @@ macro_rules! impl_uint_prop_tests_for {
                     });
                 }
 
+                #[test]
+                fn wrapping_sub_signed_unbounded(rhs in SigRhsGen) {
+                    assert_wrapping_sub_signed_unbounded(rhs, Cnst::wrapping_sub_signed);
+                }
+
+                #[test]
+                fn overflowing_sub_signed_unbounded(rhs in SigRhsGen) {
+                    assert_wrapping_sub_signed_unbounded(rhs, |cnst: Cnst, rhs| {
+                        let (cnst, overflowed) = cnst.overflowing_sub_signed(rhs);
+                        assert!(overflowed, "expected `overflowing_sub_signed` to overflow");
+                        cnst
+                    });
+                }
+
                 #[test]
                 fn wrapping_sub_unbounded(rhs in UnsRhsGen) {
                     assert_wrapping_sub_unbounded(rhs, Cnst::wrapping_sub);
@@ -945,6 +2165,16 @@ macro_rules! impl_uint_prop_tests_for {
                         cnst
                     });
                 }
+
+                #[test]
+                fn mul_consistency((cnst, rhs) in (UnsCnstGen, UnsRhsGen)) {
+                    assert_mul_consistency::<{ Cnst::MIN }, { Cnst::MAX }, { Cnst::DEF }>(cnst, rhs);
+                }
+
+                #[test]
+                fn pow_consistency((cnst, exp) in (UnsCnstGen, 0..u32::MAX)) {
+                    assert_pow_consistency::<{ Cnst::MIN }, { Cnst::MAX }, { Cnst::DEF }>(cnst, exp);
+                }
             }
         }
     )+};
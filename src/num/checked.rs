@@ -0,0 +1,136 @@
+//! Container for intentionally-checked aritmethic on `T`.
+
+/// Provides intentionally-checked arithmetic on `T`.
+///
+/// Checked arithmetic can be achieved either through methods like
+/// `checked_add`, or through the `Checked<T>` type, which says that all
+/// standard arithmetic operations on the underlying value are intended to
+/// stay within the range's inclusive bounds.
+///
+/// Once an operation leaves the range, the container is poisoned, and every
+/// following operation will preserve the first bound violation. The final
+/// value can be retrieved through [`into_option`] or, for `Constrained` types,
+/// through `into_result`, which also reports which bound was violated.
+///
+/// [`into_option`]: Checked::into_option
+///
+/// # Example
+///
+/// ```
+/// use constrained_int::u16::{ConstrainedU16, ConstrainedU16Error};
+/// use constrained_int::Checked;
+///
+/// type Constrained = ConstrainedU16<0, 1000>;
+///
+/// let lhs = Checked::new(Constrained::new(600)?);
+/// let rhs = Checked::new(Constrained::new(500)?);
+///
+/// // Stays within bounds.
+/// let checked = lhs - 200 + rhs;
+/// assert_eq!(checked.into_option().map(|cnst| cnst.get()), Some(900));
+///
+/// // Above upper bound in the middle of the chain.
+/// let checked = lhs + rhs - 200;
+/// assert!(checked.is_poisoned());
+/// assert!(matches!(checked.into_result(), Err(ConstrainedU16Error::Greater(_))));
+/// # Ok::<(), ConstrainedU16Error<0, 1000>>(())
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct Checked<T>(Result<T, Violation>);
+
+/// The range bound violated by a poisoned `Checked` container, and the bits of
+/// the rejected value if it is known, so the violation does not depend on the
+/// primitive.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+enum Violation {
+    Lower(Option<u128>),
+    Greater(Option<u128>),
+}
+
+impl<T> Checked<T> {
+    /// Creates a new container holding `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constrained_int::u8::ConstrainedU8;
+    /// use constrained_int::Checked;
+    ///
+    /// let checked = Checked::new(ConstrainedU8::<1, 254>::new_min());
+    /// assert!(!checked.is_poisoned());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(Ok(value))
+    }
+
+    /// Returns `true` if any operation has left the range's inclusive bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constrained_int::u8::ConstrainedU8;
+    /// use constrained_int::Checked;
+    ///
+    /// let checked = Checked::new(ConstrainedU8::<1, 254>::new_max());
+    /// assert!((checked + 1).is_poisoned());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_poisoned(&self) -> bool {
+        self.0.is_err()
+    }
+
+    /// Returns the contained value, or [`None`] if the container is poisoned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use constrained_int::u8::ConstrainedU8;
+    /// use constrained_int::Checked;
+    ///
+    /// let checked = Checked::new(ConstrainedU8::<1, 254>::new_min());
+    /// assert_eq!((checked + 1).into_option().map(|cnst| cnst.get()), Some(2));
+    /// assert_eq!((checked - 1).into_option(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        self.0.ok()
+    }
+}
+
+impl<T: Default> Default for Checked<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+// Implements APIs for `Checked<T>` where T is a unsigned constrained interger.
+checked_impl! {
+    { u8, u8, ConstrainedU8, ConstrainedU8Error },
+    { u16, u16, ConstrainedU16, ConstrainedU16Error },
+    { u32, u32, ConstrainedU32, ConstrainedU32Error },
+    { u64, u64, ConstrainedU64, ConstrainedU64Error },
+    { u128, u128, ConstrainedU128, ConstrainedU128Error },
+    { usize, usize, ConstrainedUsize, ConstrainedUsizeError },
+}
+
+// Implements APIs for `Checked<T>` where T is a signed constrained interger.
+checked_impl! {
+    { i8, i8, ConstrainedI8, ConstrainedI8Error },
+    { i16, i16, ConstrainedI16, ConstrainedI16Error },
+    { i32, i32, ConstrainedI32, ConstrainedI32Error },
+    { i64, i64, ConstrainedI64, ConstrainedI64Error },
+    { i128, i128, ConstrainedI128, ConstrainedI128Error },
+    { isize, isize, ConstrainedIsize, ConstrainedIsizeError },
+}
@@ -0,0 +1,233 @@
+// Implements core::ops traits for the `Checked` type.
+macro_rules! checked_ops_impl {
+    (  { $Int:ty, $Cnst:ident, $Err:ident },
+     $({ $Bop:ident($bop_f:ident), $Aop:ident($aop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {$(
+        use ::core::ops::{$Bop, $Aop};
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop<$Int> for Checked<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $bop_f(self, rhs: $Int) -> Self {
+                match self.0 {
+                    Ok(lhs) => match lhs.$inner_f(rhs) {
+                        Ok(value) => Checked(Ok(value)),
+                        Err(err) => Checked(Err(violation($Err::<MIN, MAX>::from(err)))),
+                    },
+                    Err(_) => self,
+                }
+            }
+        }
+
+        forward_ref_binop! {
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<$Int>, $bop_f for Checked<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop for Checked<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $bop_f(self, rhs: Self) -> Self {
+                // Preserves the first bound violation.
+                match (self.0, rhs.0) {
+                    (Ok(_), Ok(rhs)) => <Self as $Bop<$Int>>::$bop_f(self, rhs.get()),
+                    (Ok(_), Err(_)) => rhs,
+                    (Err(_), _) => self,
+                }
+            }
+        }
+
+        forward_ref_binop! {
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<Checked<$Cnst<MIN, MAX, DEF>>>, $bop_f for Checked<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop for Checked<$Cnst<MIN, MAX, DEF>>
+        {
+            #[inline]
+            fn $aop_f(&mut self, rhs: Self) {
+                *self = <Self as $Bop>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<Checked<$Cnst<MIN, MAX, DEF>>>, $aop_f for Checked<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop<$Int> for Checked<$Cnst<MIN, MAX, DEF>>
+        {
+            #[inline]
+            fn $aop_f(&mut self, rhs: $Int) {
+                *self = <Self as $Bop<$Int>>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<$Int>, $aop_f for Checked<$Cnst<MIN, MAX, DEF>>
+        }
+    )+};
+}
+
+// Implements APIs and tests for the `Checked` type.
+macro_rules! checked_impl {
+    ($({ $Int:ty, $md:ident, $Cnst:ident, $Err:ident }),+ $(,)?) => {$(
+        mod $md {
+            use $crate::$md::{$Cnst, $Err};
+            use super::{Checked, Violation};
+
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> Checked<$Cnst<MIN, MAX, DEF>> {
+                /// Returns the contained value, or an error indicating which bound was
+                /// violated if the container is poisoned.
+                ///
+                /// If more than one operation has left the range, the error reports the
+                /// first bound violation. The error keeps the value that violated the
+                /// bound if it was known, but not the operands that produced it.
+                ///
+                /// # Example
+                ///
+                /// ```
+                #[doc = concat!("use constrained_int::", stringify!($md), "::{", stringify!($Cnst), ", ", stringify!($Err), "};")]
+                /// use constrained_int::Checked;
+                ///
+                #[doc = concat!("type Constrained = ", stringify!($Cnst), "<1, 100>;")]
+                ///
+                /// let checked = Checked::new(Constrained::new_max());
+                /// // Above upper bound, then back within bounds.
+                /// let result = (checked + 1 - 1).into_result();
+                #[doc = concat!("assert!(matches!(result, Err(", stringify!($Err), "::Greater(_))));")]
+                /// assert_eq!(result.unwrap_err().value(), Some(101));
+                /// ```
+                #[inline]
+                pub fn into_result(self) -> Result<$Cnst<MIN, MAX, DEF>, $Err<MIN, MAX>> {
+                    match self.0 {
+                        Ok(value) => Ok(value),
+                        Err(Violation::Lower(Some(bits))) => Err($Err::lower_value(bits as $Int)),
+                        Err(Violation::Lower(None)) => Err($Err::lower()),
+                        Err(Violation::Greater(Some(bits))) => Err($Err::greater_value(bits as $Int)),
+                        Err(Violation::Greater(None)) => Err($Err::greater()),
+                    }
+                }
+            }
+
+            /// Returns the bound violated by `err`, and the bits of its value.
+            #[inline]
+            const fn violation<const MIN: $Int, const MAX: $Int>(err: $Err<MIN, MAX>) -> Violation {
+                // TODO: Can't use `Option::map` because it is not `const`.
+                let bits = match err.value() {
+                    Some(value) => Some(value as u128),
+                    None => None,
+                };
+                match err {
+                    $Err::Lower(_) => Violation::Lower(bits),
+                    $Err::Greater(_) => Violation::Greater(bits),
+                }
+            }
+
+            checked_ops_impl! {
+                { $Int, $Cnst, $Err },
+                { Add(add), AddAssign(add_assign) => try_add },
+                { Sub(sub), SubAssign(sub_assign) => try_sub },
+                { Mul(mul), MulAssign(mul_assign) => try_mul },
+            }
+
+            #[cfg(test)]
+            checked_tests! {
+                $Int, $Cnst, $Err
+            }
+        }
+    )+};
+}
+
+// Implements tests for the `Checked` type.
+#[cfg(test)]
+macro_rules! checked_tests {
+    ($Int:ty, $Cnst:ident, $Err:ident) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            type CnstTest = $Cnst<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+            #[test]
+            fn bounded() {
+                let min = Checked::new(CnstTest::new_min());
+                let max = Checked::new(CnstTest::new_max());
+
+                let checked = (min + 1) * 1 - 1;
+                assert_eq!(checked.into_result(), Ok(CnstTest::new_min()));
+
+                let checked = max - 1 + 1;
+                assert_eq!(checked.into_option(), Some(CnstTest::new_max()));
+            }
+
+            #[test]
+            fn unbounded() {
+                let min = Checked::new(CnstTest::new_min());
+                let max = Checked::new(CnstTest::new_max());
+
+                assert_eq!((min - 1).into_result(), Err($Err::lower_value(<$Int>::MIN)));
+                assert_eq!((max + 1).into_result(), Err($Err::greater_value(<$Int>::MAX)));
+                // The value is unknown if the primitive overflows.
+                assert_eq!((max * 2).into_result(), Err($Err::greater()));
+                assert_eq!((max + max).into_option(), None);
+            }
+
+            #[test]
+            fn poison_is_sticky() {
+                let min = Checked::new(CnstTest::new_min());
+                let max = Checked::new(CnstTest::new_max());
+
+                // Lhs violation is preserved, even if the result is back in range.
+                let checked = max + 1 - 1;
+                assert!(checked.is_poisoned());
+                assert_eq!(checked.into_result(), Err($Err::greater_value(<$Int>::MAX)));
+
+                // The first violation is preserved over later ones.
+                let checked = (min - 1) + max + 1;
+                assert_eq!(checked.into_result(), Err($Err::lower_value(<$Int>::MIN)));
+
+                // Rhs violation is propagated.
+                let checked = min + (max + 1);
+                assert_eq!(checked.into_result(), Err($Err::greater_value(<$Int>::MAX)));
+            }
+
+            #[test]
+            fn op_assign() {
+                let one = Checked::new(CnstTest::new(1).unwrap());
+                let mut checked = one;
+
+                checked += 1;
+                checked -= &1;
+                checked *= one;
+                checked += &one;
+                assert_eq!(checked.into_option(), CnstTest::new(2).ok());
+
+                let mut checked = Checked::new(CnstTest::new_max());
+                checked += 1;
+                checked -= 2;
+                assert_eq!(checked.into_result(), Err($Err::greater_value(<$Int>::MAX)));
+            }
+
+            #[test]
+            fn ref_ops() {
+                let one = Checked::new(CnstTest::new(1).unwrap());
+                let checked = one * 2;
+
+                assert_eq!(&checked + 1, checked + 1);
+                assert_eq!(checked * &1, checked * 1);
+                assert_eq!(&checked + &one, checked + 1);
+                assert_eq!(&checked * one, checked);
+            }
+        }
+    };
+}
@@ -73,6 +73,22 @@ macro_rules! arithmetic_wrapper_ops_impl {
                 $Bop<$Wrapper<$Cnst<MIN, MAX, DEF>>>, $bop_f for $Wrapper<$Cnst<MIN, MAX, DEF>>
         }
 
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+            $Bop<$Int> for $Wrapper<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $bop_f(self, rhs: $Int) -> Self {
+                $Wrapper(self.0.$inner_f(rhs))
+            }
+        }
+
+        forward_ref_binop! {
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+                $Bop<$Int>, $bop_f for $Wrapper<$Cnst<MIN, MAX, DEF>>
+        }
+
         impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
             $Aop for $Wrapper<$Cnst<MIN, MAX, DEF>>
         {
@@ -103,6 +119,110 @@ macro_rules! arithmetic_wrapper_ops_impl {
     )+};
 }
 
+// Implements commutative core::ops traits for $Int, with $Wrapper as the rhs.
+macro_rules! arithmetic_wrapper_commutative_ops_impl {
+    (  { $Int:ty, $Cnst:ident, $Wrapper:ident },
+     $({ $Bop:ident($bop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {
+        $(
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+                $Bop<$Wrapper<$Cnst<MIN, MAX, DEF>>> for $Int
+            {
+                type Output = $Wrapper<$Cnst<MIN, MAX, DEF>>;
+
+                #[inline]
+                fn $bop_f(self, rhs: $Wrapper<$Cnst<MIN, MAX, DEF>>) -> Self::Output {
+                    $Wrapper(rhs.0.$inner_f(self))
+                }
+            }
+
+            forward_ref_binop! {
+                impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+                    $Bop<$Wrapper<$Cnst<MIN, MAX, DEF>>>, $bop_f for $Int
+            }
+        )+
+
+        #[cfg(test)]
+        arithmetic_wrapper_commutative_ops_tests! {
+            { $Int, $Cnst, $Wrapper },
+          $({ $Bop($bop_f) => $inner_f }),+
+        }
+    };
+}
+
+// Implements core::ops traits for $Wrapper, with a $Rhs integer of the opposite
+// signedness as the rhs.
+macro_rules! arithmetic_wrapper_mixed_ops_impl {
+    (  { $Int:ty, $Rhs:ty, $Cnst:ident, $Wrapper:ident },
+     $({ $Bop:ident($bop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {
+        $(
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+                $Bop<$Rhs> for $Wrapper<$Cnst<MIN, MAX, DEF>>
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $bop_f(self, rhs: $Rhs) -> Self {
+                    $Wrapper(self.0.$inner_f(rhs))
+                }
+            }
+
+            forward_ref_binop! {
+                impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> const
+                    $Bop<$Rhs>, $bop_f for $Wrapper<$Cnst<MIN, MAX, DEF>>
+            }
+        )+
+
+        #[cfg(test)]
+        arithmetic_wrapper_mixed_ops_tests! {
+            { $Int, $Rhs, $Cnst, $Wrapper },
+          $({ $Bop($bop_f) => $inner_f }),+
+        }
+    };
+}
+
+// Implements core::iter traits that fold an iterator for $Wrapper. Empty
+// iterators return the `$empty` value, constructed with `$new_f`.
+macro_rules! arithmetic_wrapper_iter_impl {
+    (  { $Int:ty, $md:ident, $Cnst:ident, $Wrapper:ident, $new_f:ident },
+     $({ $Trait:ident($trait_f:ident) => $inner_f:ident, $empty:literal }),+ $(,)?
+    ) => {
+        $(
+            // Guard the constructor for empty iterators.
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                ::core::iter::$Trait for $Wrapper<$Cnst<MIN, MAX, DEF>>
+            where
+                $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+            {
+                fn $trait_f<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    match iter.reduce(|acc, wrapper| $Wrapper(acc.0.$inner_f(wrapper.0.get()))) {
+                        Some(wrapper) => wrapper,
+                        None => $Wrapper($Cnst::$new_f($empty)),
+                    }
+                }
+            }
+
+            // Guard the constructor for empty iterators.
+            impl<'a, const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                ::core::iter::$Trait<&'a Self> for $Wrapper<$Cnst<MIN, MAX, DEF>>
+            where
+                $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+            {
+                fn $trait_f<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    <Self as ::core::iter::$Trait>::$trait_f(iter.copied())
+                }
+            }
+        )+
+
+        #[cfg(test)]
+        arithmetic_wrapper_iter_tests! {
+            { $Int, $Cnst, $Wrapper, $new_f },
+          $({ $Trait($trait_f) => $inner_f, $empty }),+
+        }
+    };
+}
+
 // Implements common APIs and tests for $Wrapper.
 macro_rules! arithmetic_wrapper_common {
     (  { $Int:ty, $Cnst:ident, $Wrapper:tt, $test_mod:ident },
@@ -241,6 +361,28 @@ macro_rules! arithmetic_wrapper_binop_tests {
 
                 assert_eq!(cnst.get(), wrapper.0.get());
             }
+
+            #[test]
+            fn copy_lhs_prim_rhs() {
+                let mut cnst = CnstTest::default();
+                let mut wrapper = $Wrapper(cnst);
+
+                cnst = cnst.$inner_f(cnst.get());
+                wrapper = <$Wrapper<_> as $Bop<_>>::$bop_f(wrapper, wrapper.0.get());
+
+                assert_eq!(cnst.get(), wrapper.0.get());
+            }
+
+            #[test]
+            fn ref_lhs_prim_ref_rhs() {
+                let mut cnst = CnstTest::default();
+                let mut wrapper = $Wrapper(cnst);
+
+                cnst = cnst.$inner_f(cnst.get());
+                wrapper = <&$Wrapper<_> as $Bop<_>>::$bop_f(&wrapper, &wrapper.0.get());
+
+                assert_eq!(cnst.get(), wrapper.0.get());
+            }
         }
     )+};
 }
@@ -305,3 +447,119 @@ macro_rules! aritmetic_wrapper_op_assign_tests {
         }
     )+};
 }
+
+// Verify that commutative operators with a primitive lhs work as expected for
+// arithmetic wrappers.
+#[cfg(test)]
+macro_rules! arithmetic_wrapper_commutative_ops_tests {
+    (  { $Int:ty, $Cnst:ident, $Wrapper:ident },
+     $({ $Bop:ident($bop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {
+        #[cfg(test)]
+        mod tests_commutative_ops {$(
+            mod $bop_f {
+                use ::core::ops::$Bop;
+                use super::super::*;
+
+                type CnstTest = $Cnst<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+                #[test]
+                fn prim_lhs_copy_rhs() {
+                    let cnst = CnstTest::new_max();
+                    let wrapper = <$Int as $Bop<_>>::$bop_f(cnst.get(), $Wrapper(cnst));
+                    assert_eq!(cnst.$inner_f(cnst.get()), wrapper.0);
+                }
+
+                #[test]
+                fn prim_ref_lhs_ref_rhs() {
+                    let cnst = CnstTest::new_max();
+                    let wrapper = <&$Int as $Bop<_>>::$bop_f(&cnst.get(), &$Wrapper(cnst));
+                    assert_eq!(cnst.$inner_f(cnst.get()), wrapper.0);
+                }
+            }
+        )+}
+    };
+}
+
+// Verify that operators with a rhs of the opposite signedness work as expected
+// for arithmetic wrappers.
+#[cfg(test)]
+macro_rules! arithmetic_wrapper_mixed_ops_tests {
+    (  { $Int:ty, $Rhs:ty, $Cnst:ident, $Wrapper:ident },
+     $({ $Bop:ident($bop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {
+        #[cfg(test)]
+        mod tests_mixed_ops {$(
+            mod $bop_f {
+                use ::core::ops::$Bop;
+                use super::super::*;
+
+                type CnstTest = $Cnst<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+                #[test]
+                fn copy_lhs_copy_rhs() {
+                    let cnst = CnstTest::default();
+                    for rhs in [<$Rhs>::MIN, 0, 1, <$Rhs>::MAX] {
+                        let wrapper = <$Wrapper<_> as $Bop<_>>::$bop_f($Wrapper(cnst), rhs);
+                        assert_eq!(cnst.$inner_f(rhs), wrapper.0);
+                    }
+                }
+
+                #[test]
+                fn ref_lhs_ref_rhs() {
+                    let cnst = CnstTest::default();
+                    for rhs in [<$Rhs>::MIN, 0, 1, <$Rhs>::MAX] {
+                        let wrapper = <&$Wrapper<_> as $Bop<_>>::$bop_f(&$Wrapper(cnst), &rhs);
+                        assert_eq!(cnst.$inner_f(rhs), wrapper.0);
+                    }
+                }
+            }
+        )+}
+    };
+}
+
+// Verify that iterator folding traits work as expected for arithmetic wrappers.
+#[cfg(test)]
+macro_rules! arithmetic_wrapper_iter_tests {
+    (  { $Int:ty, $Cnst:ident, $Wrapper:ident, $new_f:ident },
+     $({ $Trait:ident($trait_f:ident) => $inner_f:ident, $empty:literal }),+ $(,)?
+    ) => {
+        #[cfg(test)]
+        mod tests_iter {$(
+            mod $trait_f {
+                use super::super::*;
+
+                type CnstTest = $Cnst<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+                #[test]
+                fn owned_and_ref_items() {
+                    let values = [CnstTest::new_max(), CnstTest::new_max(), CnstTest::new_min()];
+                    let expected = values[1..].iter().fold(values[0], |acc, cnst| acc.$inner_f(cnst.get()));
+                    let wrappers = values.map($Wrapper);
+
+                    let wrapper: $Wrapper<_> = wrappers.iter().$trait_f();
+                    assert_eq!(wrapper.0, expected);
+
+                    let wrapper: $Wrapper<_> = wrappers.into_iter().$trait_f();
+                    assert_eq!(wrapper.0, expected);
+                }
+
+                #[test]
+                fn single_item() {
+                    let wrapper: $Wrapper<_> = [$Wrapper(CnstTest::new_max())].into_iter().$trait_f();
+                    assert_eq!(wrapper.0, CnstTest::new_max());
+                }
+
+                #[test]
+                fn empty() {
+                    let wrapper: $Wrapper<CnstTest> = ::core::iter::empty::<$Wrapper<_>>().$trait_f();
+                    assert_eq!(wrapper.0, CnstTest::$new_f($empty));
+
+                    type SmallTest = $Cnst<2, 10>;
+                    let wrapper: $Wrapper<SmallTest> = ::core::iter::empty::<$Wrapper<_>>().$trait_f();
+                    assert_eq!(wrapper.0, SmallTest::$new_f($empty));
+                }
+            }
+        )+}
+    };
+}
@@ -24,7 +24,7 @@ macro_rules! arithmetic_wrapper_int_specific_impl {
             #[doc = concat!("value = ", stringify!($Wrapper), "(Constrained::new_max());")]
             /// assert_eq!(value.signum(), 1);
             /// ```
-            #[must_use = "this returns the result of the operation, without modifyind the original"]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
             #[inline]
             pub const fn signum(self) -> $SigInt {
                 self.0.signum()
@@ -70,6 +70,34 @@ macro_rules! arithmetic_wrapper_int_specific_impl {
     };
 }
 
+// Implements core::ops::Neg for $Wrapper, based on the given inner negation.
+macro_rules! arithmetic_wrapper_neg_impl {
+    ($SigInt:ty, $Cnst:ident, $Wrapper:ident, $inner_f:ident) => {
+        use ::core::ops::Neg;
+
+        impl<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt> const Neg
+            for $Wrapper<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $Wrapper(self.0.$inner_f())
+            }
+        }
+
+        forward_ref_unop! {
+            impl<const MIN: $SigInt, const MAX: $SigInt, const DEF: $SigInt> const
+                Neg, neg for $Wrapper<$Cnst<MIN, MAX, DEF>>
+        }
+
+        #[cfg(test)]
+        arithmetic_wrapper_neg_tests! {
+            $Cnst, $Wrapper, $inner_f
+        }
+    };
+}
+
 // Implements APIs and tests specific to signed integers.
 macro_rules! arithmetic_wrapper_int_specific {
     ($SigInt:ty, $md:ident, $Cnst:ident, $Wrapper:ident) => {
@@ -136,3 +164,33 @@ macro_rules! arithmetic_wrapper_int_specific_tests {
         }
     };
 }
+
+// Verify that the negation operator works as expected for arithmetic wrappers.
+#[cfg(test)]
+macro_rules! arithmetic_wrapper_neg_tests {
+    ($Cnst:ident, $Wrapper:ident, $inner_f:ident) => {
+        mod tests_neg {
+            use super::*;
+
+            type CnstTest = $Cnst<-5, 10>;
+
+            #[test]
+            fn copy_operand() {
+                for value in CnstTest::range() {
+                    let cnst = CnstTest::new(value).unwrap();
+                    let wrapper = <$Wrapper<_> as Neg>::neg($Wrapper(cnst));
+                    assert_eq!(wrapper.0, cnst.$inner_f());
+                }
+            }
+
+            #[test]
+            fn ref_operand() {
+                for value in CnstTest::range() {
+                    let cnst = CnstTest::new(value).unwrap();
+                    let wrapper = <&$Wrapper<_> as Neg>::neg(&$Wrapper(cnst));
+                    assert_eq!(wrapper.0, cnst.$inner_f());
+                }
+            }
+        }
+    };
+}
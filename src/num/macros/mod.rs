@@ -1,10 +1,14 @@
 // Import:
 // - `arithmetic_wrapper_common!`.
+// - `arithmetic_wrapper_commutative_ops_impl!`.
+// - `arithmetic_wrapper_mixed_ops_impl!`.
+// - `arithmetic_wrapper_iter_impl!`.
 #[macro_use]
 mod common;
 
 // Import:
 // - `arithmetic_wrapper_int_specific!`.
+// - `arithmetic_wrapper_neg_impl!`.
 #[macro_use]
 mod int;
 
@@ -14,7 +18,11 @@ mod int;
 //
 // Required:
 // - `arithmetic_wrapper_common!`.
+// - `arithmetic_wrapper_commutative_ops_impl!`.
+// - `arithmetic_wrapper_mixed_ops_impl!`.
+// - `arithmetic_wrapper_iter_impl!`.
 // - `arithmetic_wrapper_int_specific!`.
+// - `arithmetic_wrapper_neg_impl!`.
 #[macro_use]
 mod wrapping;
 
@@ -24,6 +32,20 @@ mod wrapping;
 //
 // Required:
 // - `arithmetic_wrapper_common!`.
+// - `arithmetic_wrapper_commutative_ops_impl!`.
+// - `arithmetic_wrapper_mixed_ops_impl!`.
+// - `arithmetic_wrapper_iter_impl!`.
 // - `arithmetic_wrapper_int_specific!`.
+// - `arithmetic_wrapper_neg_impl!`.
 #[macro_use]
 mod saturating;
+
+// Import:
+// - `checked_impl!`.
+#[macro_use]
+mod checked;
+
+// Import:
+// - `strict_impl!`.
+#[macro_use]
+mod strict;
@@ -1,41 +1,72 @@
 // Implements common APIs and tests for the `Saturating` type.
 macro_rules! saturating_common {
-    ($Int:ty, $Cnst:ident, $test_mod:ident) => {
+    ($Int:ty, $md:ident, $Cnst:ident, $test_mod:ident) => {
         arithmetic_wrapper_common! {
             { $Int, $Cnst, Saturating, $test_mod },
             { Add(add), AddAssign(add_assign) => saturating_add },
             { Sub(sub), SubAssign(sub_assign) => saturating_sub },
+            { Mul(mul), MulAssign(mul_assign) => saturating_mul },
+            { Div(div), DivAssign(div_assign) => saturating_div_op },
+            { Rem(rem), RemAssign(rem_assign) => saturating_rem_op },
+        }
+
+        arithmetic_wrapper_commutative_ops_impl! {
+            { $Int, $Cnst, Saturating },
+            { Add(add) => saturating_add },
+            { Mul(mul) => saturating_mul },
+        }
+
+        arithmetic_wrapper_iter_impl! {
+            { $Int, $md, $Cnst, Saturating, saturating_new },
+            { Sum(sum) => saturating_add, 0 },
+            { Product(product) => saturating_mul, 1 },
         }
     };
 }
 
 // Implements common and signed specific APIs and tests for the `Saturating` type.
 macro_rules! saturating_int {
-    ($({ $SigInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+    ($({ $SigInt:ty, $UnsInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
         mod $md {
             use $crate::$md::$Cnst;
             use super::Saturating;
 
             saturating_common! {
-                $SigInt, $Cnst, tests_int_common
+                $SigInt, $md, $Cnst, tests_int_common
             }
 
             arithmetic_wrapper_int_specific! {
                 $SigInt, $md, $Cnst, Saturating
             }
+
+            arithmetic_wrapper_mixed_ops_impl! {
+                { $SigInt, $UnsInt, $Cnst, Saturating },
+                { Add(add) => saturating_add_unsigned },
+                { Sub(sub) => saturating_sub_unsigned },
+            }
+
+            arithmetic_wrapper_neg_impl! {
+                $SigInt, $Cnst, Saturating, saturating_neg
+            }
         }
     )+};
 }
 
 // Implements common and unsigned specific APIs and tests for the `Saturating` type.
 macro_rules! saturating_uint {
-    ($({ $UnsInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+    ($({ $UnsInt:ty, $SigInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
         mod $md {
             use $crate::$md::$Cnst;
             use super::Saturating;
 
             saturating_common! {
-                $UnsInt, $Cnst, test_uint_common
+                $UnsInt, $md, $Cnst, test_uint_common
+            }
+
+            arithmetic_wrapper_mixed_ops_impl! {
+                { $UnsInt, $SigInt, $Cnst, Saturating },
+                { Add(add) => saturating_add_signed },
+                { Sub(sub) => saturating_sub_signed },
             }
         }
     )+};
@@ -0,0 +1,157 @@
+// Implements core::ops traits for the `Strict` type.
+macro_rules! strict_ops_impl {
+    (  { $Int:ty, $Cnst:ident },
+     $({ $Bop:ident($bop_f:ident), $Aop:ident($aop_f:ident) => $inner_f:ident }),+ $(,)?
+    ) => {$(
+        use ::core::ops::{$Bop, $Aop};
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop<$Int> for Strict<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $bop_f(self, rhs: $Int) -> Self {
+                match self.0.$inner_f(rhs) {
+                    Ok(value) => Strict(value),
+                    Err(err) => panic!("{err}"),
+                }
+            }
+        }
+
+        forward_ref_binop! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<$Int>, $bop_f for Strict<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Bop for Strict<$Cnst<MIN, MAX, DEF>>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $bop_f(self, rhs: Self) -> Self {
+                <Self as $Bop<$Int>>::$bop_f(self, rhs.0.get())
+            }
+        }
+
+        forward_ref_binop! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Bop<Strict<$Cnst<MIN, MAX, DEF>>>, $bop_f for Strict<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop for Strict<$Cnst<MIN, MAX, DEF>>
+        {
+            #[inline]
+            #[track_caller]
+            fn $aop_f(&mut self, rhs: Self) {
+                *self = <Self as $Bop>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<Strict<$Cnst<MIN, MAX, DEF>>>, $aop_f for Strict<$Cnst<MIN, MAX, DEF>>
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+            $Aop<$Int> for Strict<$Cnst<MIN, MAX, DEF>>
+        {
+            #[inline]
+            #[track_caller]
+            fn $aop_f(&mut self, rhs: $Int) {
+                *self = <Self as $Bop<$Int>>::$bop_f(*self, rhs);
+            }
+        }
+
+        forward_ref_op_assign! {
+            #[track_caller]
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int>
+                $Aop<$Int>, $aop_f for Strict<$Cnst<MIN, MAX, DEF>>
+        }
+    )+};
+}
+
+// Implements APIs and tests for the `Strict` type.
+macro_rules! strict_impl {
+    ($({ $Int:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+        mod $md {
+            use $crate::$md::$Cnst;
+            use super::Strict;
+
+            strict_ops_impl! {
+                { $Int, $Cnst },
+                { Add(add), AddAssign(add_assign) => try_add },
+                { Sub(sub), SubAssign(sub_assign) => try_sub },
+            }
+
+            #[cfg(test)]
+            strict_tests! {
+                $Int, $Cnst
+            }
+        }
+    )+};
+}
+
+// Implements tests for the `Strict` type.
+#[cfg(test)]
+macro_rules! strict_tests {
+    ($Int:ty, $Cnst:ident) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            type CnstTest = $Cnst<{ <$Int>::MIN + 1 }, { <$Int>::MAX - 1 }>;
+
+            #[test]
+            fn bounded() {
+                let min = Strict(CnstTest::new_min());
+                let max = Strict(CnstTest::new_max());
+                let one = Strict(CnstTest::new(1).unwrap());
+
+                assert_eq!((min + 1 - 1).0, CnstTest::new_min());
+                assert_eq!((&max - 1 + &1).0, CnstTest::new_max());
+                assert_eq!((max - one + one).0, CnstTest::new_max());
+
+                let mut strict = min;
+                strict += 1;
+                strict -= &1;
+                strict += one;
+                strict -= &one;
+                assert_eq!(strict, min);
+            }
+
+            #[test]
+            #[should_panic(expected = "value must be lower or equal to")]
+            fn add_unbounded() {
+                let _ = Strict(CnstTest::new_max()) + 1;
+            }
+
+            #[test]
+            #[should_panic(expected = "value must be greater or equal to")]
+            fn sub_unbounded() {
+                let _ = &Strict(CnstTest::new_min()) - &1;
+            }
+
+            #[test]
+            #[should_panic(expected = "value must be lower or equal to")]
+            fn add_assign_unbounded() {
+                let mut strict = Strict(CnstTest::new_max());
+                strict += strict;
+            }
+
+            #[test]
+            #[should_panic(expected = "value must be greater or equal to")]
+            fn sub_assign_unbounded() {
+                let mut strict = Strict(CnstTest::new_min());
+                strict -= 1;
+            }
+        }
+    };
+}
@@ -1,41 +1,72 @@
 // Implements common APIs and tests for the `Wrapping` type.
 macro_rules! wrapping_common {
-    ($Int:ty, $Cnst:ident, $test_mod:ident) => {
+    ($Int:ty, $md:ident, $Cnst:ident, $test_mod:ident) => {
         arithmetic_wrapper_common! {
             { $Int, $Cnst, Wrapping, $test_mod },
             { Add(add), AddAssign(add_assign) => wrapping_add },
             { Sub(sub), SubAssign(sub_assign) => wrapping_sub },
+            { Mul(mul), MulAssign(mul_assign) => wrapping_mul },
+            { Div(div), DivAssign(div_assign) => wrapping_div_op },
+            { Rem(rem), RemAssign(rem_assign) => wrapping_rem_op },
+        }
+
+        arithmetic_wrapper_commutative_ops_impl! {
+            { $Int, $Cnst, Wrapping },
+            { Add(add) => wrapping_add },
+            { Mul(mul) => wrapping_mul },
+        }
+
+        arithmetic_wrapper_iter_impl! {
+            { $Int, $md, $Cnst, Wrapping, wrapping_new },
+            { Sum(sum) => wrapping_add, 0 },
+            { Product(product) => wrapping_mul, 1 },
         }
     };
 }
 
 // Implements common and signed specific APIs and tests for the `Wrapping` type.
 macro_rules! wrapping_int {
-    ($({ $SigInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+    ($({ $SigInt:ty, $UnsInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
         mod $md {
             use $crate::$md::$Cnst;
             use super::Wrapping;
 
             wrapping_common! {
-                $SigInt, $Cnst, tests_int_common
+                $SigInt, $md, $Cnst, tests_int_common
             }
 
             arithmetic_wrapper_int_specific! {
                 $SigInt, $md, $Cnst, Wrapping
             }
+
+            arithmetic_wrapper_mixed_ops_impl! {
+                { $SigInt, $UnsInt, $Cnst, Wrapping },
+                { Add(add) => wrapping_add_unsigned },
+                { Sub(sub) => wrapping_sub_unsigned },
+            }
+
+            arithmetic_wrapper_neg_impl! {
+                $SigInt, $Cnst, Wrapping, wrapping_neg
+            }
         }
     )+};
 }
 
 // Implements common and unsigned specific APIs and tests for the `Wrapping` type.
 macro_rules! wrapping_uint {
-    ($({ $UnsInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+    ($({ $UnsInt:ty, $SigInt:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
         mod $md {
             use $crate::$md::$Cnst;
             use super::Wrapping;
 
             wrapping_common! {
-                $UnsInt, $Cnst, test_uint_common
+                $UnsInt, $md, $Cnst, test_uint_common
+            }
+
+            arithmetic_wrapper_mixed_ops_impl! {
+                { $UnsInt, $SigInt, $Cnst, Wrapping },
+                { Add(add) => wrapping_add_signed },
+                { Sub(sub) => wrapping_sub_signed },
             }
         }
     )+};
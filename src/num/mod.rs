@@ -4,6 +4,8 @@
 // - `wrapping_uint!`.
 // - `saturating_int!`.
 // - `saturating_uint!`.
+// - `checked_impl!`.
+// - `strict_impl!`.
 #[macro_use]
 mod macros;
 
@@ -20,3 +22,14 @@ pub use wrapping::Wrapping;
 // - `saturating_uint!`.
 mod saturating;
 pub use saturating::Saturating;
+
+// Requires:
+// - `checked_impl!`.
+mod checked;
+pub use checked::Checked;
+
+// Requires:
+// - `arithmetic_wrapper_traits_impl!`.
+// - `strict_impl!`.
+mod strict;
+pub use strict::Strict;
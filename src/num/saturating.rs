@@ -14,6 +14,20 @@
 ///
 /// `Saturating<T>` is guaranteed to have the same layout and ABI as `T`.
 ///
+/// # Mixed operands
+///
+/// Binary operators also accept a bare integer as the rhs, `+` and `*` accept it
+/// as the lhs too, and `+` and `-` accept an integer of the opposite signedness
+/// as the rhs. Since more than one rhs type is accepted, integer literals used as
+/// the rhs must carry a type suffix.
+///
+/// # Sum and product
+///
+/// `Sum` and `Product` fold the iterator with the saturating operators, so like
+/// primitive saturating arithmetic, the result depends on the items' order. Empty
+/// iterators return zero and one respectively, saturated to the range's bounds
+/// if needed.
+///
 /// # Example
 ///
 /// ```
@@ -29,6 +43,9 @@
 ///
 /// saturated = saturated - saturated - saturated;
 /// assert_eq!(saturated.0.get(), -10);
+///
+/// saturated = 2 * saturated + 25u8;
+/// assert_eq!(saturated.0.get(), 12);
 /// ```
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash)]
 #[repr(transparent)]
@@ -39,20 +56,20 @@ arithmetic_wrapper_traits_impl! { Saturating }
 
 // Implements APIs for `Saturating<T>` where T is a unsigned constrained interger.
 saturating_uint! {
-    { u8, u8, ConstrainedU8 },
-    { u16, u16, ConstrainedU16 },
-    { u32, u32, ConstrainedU32 },
-    { u64, u64, ConstrainedU64 },
-    { u128, u128, ConstrainedU128 },
-    { usize, usize, ConstrainedUsize },
+    { u8, i8, u8, ConstrainedU8 },
+    { u16, i16, u16, ConstrainedU16 },
+    { u32, i32, u32, ConstrainedU32 },
+    { u64, i64, u64, ConstrainedU64 },
+    { u128, i128, u128, ConstrainedU128 },
+    { usize, isize, usize, ConstrainedUsize },
 }
 
 // Implements APIs for `Saturating<T>` where T is a signed constrained interger.
 saturating_int! {
-    { i8, i8, ConstrainedI8 },
-    { i16, i16, ConstrainedI16 },
-    { i32, i32, ConstrainedI32 },
-    { i64, i64, ConstrainedI64 },
-    { i128, i128, ConstrainedI128 },
-    { isize, isize, ConstrainedIsize },
+    { i8, u8, i8, ConstrainedI8 },
+    { i16, u16, i16, ConstrainedI16 },
+    { i32, u32, i32, ConstrainedI32 },
+    { i64, u64, i64, ConstrainedI64 },
+    { i128, u128, i128, ConstrainedI128 },
+    { isize, usize, isize, ConstrainedIsize },
 }
@@ -0,0 +1,69 @@
+//! Container for strictly-bounded aritmethic on `T`.
+
+/// Provides strictly-bounded arithmetic on `T`.
+///
+/// Fallible arithmetic can be achieved either through methods like `try_add`,
+/// or through the `Strict<T>` type, which says that all standard arithmetic
+/// operations on the underlying value are never expected to leave the range's
+/// inclusive bounds. If an operation does leave the range, it panics with the
+/// message of the bound violation error, reporting the caller's location.
+///
+/// The underlying value can be retrieved through the `.0` index of the
+/// `Strict` tuple.
+///
+/// # Layout
+///
+/// `Strict<T>` is guaranteed to have the same layout and ABI as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use constrained_int::i8::ConstrainedI8;
+/// use constrained_int::Strict;
+///
+/// // Default set to 0.
+/// type Strictly = Strict<ConstrainedI8<-10, 12, 0>>;
+///
+/// let mut strict = Strictly::default();
+/// strict += 12;
+/// assert_eq!(strict.0.get(), 12);
+///
+/// strict = strict - strict - 10;
+/// assert_eq!(strict.0.get(), -10);
+/// ```
+///
+/// Leaving the range panics.
+/// ```should_panic
+/// use constrained_int::i8::ConstrainedI8;
+/// use constrained_int::Strict;
+///
+/// let strict = Strict(ConstrainedI8::<-10, 12>::new_max());
+/// // Panics with: "value must be lower or equal to 12, found 12 + 1".
+/// let _ = strict + 1;
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Hash)]
+#[repr(transparent)]
+pub struct Strict<T>(pub T);
+
+// Implements common traits for `Strict<T>` when the generic T implements them.
+arithmetic_wrapper_traits_impl! { Strict }
+
+// Implements APIs for `Strict<T>` where T is a unsigned constrained interger.
+strict_impl! {
+    { u8, u8, ConstrainedU8 },
+    { u16, u16, ConstrainedU16 },
+    { u32, u32, ConstrainedU32 },
+    { u64, u64, ConstrainedU64 },
+    { u128, u128, ConstrainedU128 },
+    { usize, usize, ConstrainedUsize },
+}
+
+// Implements APIs for `Strict<T>` where T is a signed constrained interger.
+strict_impl! {
+    { i8, i8, ConstrainedI8 },
+    { i16, i16, ConstrainedI16 },
+    { i32, i32, ConstrainedI32 },
+    { i64, i64, ConstrainedI64 },
+    { i128, i128, ConstrainedI128 },
+    { isize, isize, ConstrainedIsize },
+}
@@ -0,0 +1,157 @@
+// Implements `num_traits` traits for `Constrained` types, and for `Wrapping` and
+// `Saturating` over them. Conversions from other numbers are checked against the
+// range's bounds, and return `None` if the value is not contained by the range.
+macro_rules! constrained_num_traits_impl {
+    ($({ $Int:ty, $md:ident, $Cnst:ident }),+ $(,)?) => {$(
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::Bounded
+            for $crate::$md::$Cnst<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            #[inline]
+            fn min_value() -> Self {
+                Self::new_min()
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::new_max()
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::ToPrimitive
+            for $crate::$md::$Cnst<MIN, MAX, DEF>
+        {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                ::num_traits::ToPrimitive::to_i64(&self.get())
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                ::num_traits::ToPrimitive::to_u64(&self.get())
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                ::num_traits::ToPrimitive::to_i128(&self.get())
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                ::num_traits::ToPrimitive::to_u128(&self.get())
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::FromPrimitive
+            for $crate::$md::$Cnst<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                <$Int as ::num_traits::FromPrimitive>::from_i64(n).and_then(Self::checked_new)
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                <$Int as ::num_traits::FromPrimitive>::from_u64(n).and_then(Self::checked_new)
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                <$Int as ::num_traits::FromPrimitive>::from_i128(n).and_then(Self::checked_new)
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                <$Int as ::num_traits::FromPrimitive>::from_u128(n).and_then(Self::checked_new)
+            }
+        }
+
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::NumCast
+            for $crate::$md::$Cnst<MIN, MAX, DEF>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            #[inline]
+            fn from<T: ::num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                <$Int as ::num_traits::NumCast>::from(n).and_then(Self::checked_new)
+            }
+        }
+
+        constrained_num_traits_op_impl! {
+            { $Int, $md, $Cnst },
+            CheckedAdd::checked_add(&self, v: &Self) -> Option<Self>,
+            CheckedSub::checked_sub(&self, v: &Self) -> Option<Self>,
+            SaturatingAdd::saturating_add(&self, v: &Self) -> Self,
+            SaturatingSub::saturating_sub(&self, v: &Self) -> Self,
+            WrappingAdd::wrapping_add(&self, v: &Self) -> Self,
+            WrappingSub::wrapping_sub(&self, v: &Self) -> Self,
+        }
+
+        num_traits_wrapper_impl! {
+            { $Int, $md, $Cnst, Wrapping },
+            WrappingAdd::wrapping_add,
+            WrappingSub::wrapping_sub,
+        }
+
+        num_traits_wrapper_impl! {
+            { $Int, $md, $Cnst, Saturating },
+            SaturatingAdd::saturating_add,
+            SaturatingSub::saturating_sub,
+        }
+    )+};
+}
+
+// Implements `num_traits` binary operation traits for `Constrained` types,
+// forwarding to their inherent API of the same name.
+macro_rules! constrained_num_traits_op_impl {
+    (  { $Int:ty, $md:ident, $Cnst:ident },
+     $($Trait:ident::$f:ident(&self, v: &Self) -> $Ret:ty),+ $(,)?
+    ) => {$(
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::$Trait
+            for $crate::$md::$Cnst<MIN, MAX, DEF>
+        {
+            #[inline]
+            fn $f(&self, v: &Self) -> $Ret {
+                Self::$f(*self, v.get())
+            }
+        }
+    )+};
+}
+
+// Implements `Bounded` and the operation traits matching the wrapper's semantics
+// for `Wrapping` and `Saturating`, forwarding to the inner value's inherent API.
+macro_rules! num_traits_wrapper_impl {
+    (  { $Int:ty, $md:ident, $Cnst:ident, $Wrapper:ident },
+     $($Trait:ident::$f:ident),+ $(,)?
+    ) => {
+        impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::Bounded
+            for $crate::$Wrapper<$crate::$md::$Cnst<MIN, MAX, DEF>>
+        where
+            $crate::Constraints<{ $crate::$md::guard_construction::<MIN, MAX, DEF>() }>: $crate::Guard,
+        {
+            #[inline]
+            fn min_value() -> Self {
+                $crate::$Wrapper(::num_traits::Bounded::min_value())
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $crate::$Wrapper(::num_traits::Bounded::max_value())
+            }
+        }
+
+        $(
+            impl<const MIN: $Int, const MAX: $Int, const DEF: $Int> ::num_traits::$Trait
+                for $crate::$Wrapper<$crate::$md::$Cnst<MIN, MAX, DEF>>
+            {
+                #[inline]
+                fn $f(&self, v: &Self) -> Self {
+                    $crate::$Wrapper(self.0.$f(v.0.get()))
+                }
+            }
+        )+
+    };
+}
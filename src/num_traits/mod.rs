@@ -0,0 +1,20 @@
+// Import all macros.
+#[macro_use]
+mod macros;
+
+// Format:
+//  { int, int_mod, TypeName },+
+constrained_num_traits_impl! {
+    { u8, u8, ConstrainedU8 },
+    { u16, u16, ConstrainedU16 },
+    { u32, u32, ConstrainedU32 },
+    { u64, u64, ConstrainedU64 },
+    { u128, u128, ConstrainedU128 },
+    { usize, usize, ConstrainedUsize },
+    { i8, i8, ConstrainedI8 },
+    { i16, i16, ConstrainedI16 },
+    { i32, i32, ConstrainedI32 },
+    { i64, i64, ConstrainedI64 },
+    { i128, i128, ConstrainedI128 },
+    { isize, isize, ConstrainedIsize },
+}
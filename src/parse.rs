@@ -0,0 +1,102 @@
+//! Error type for strings that are not valid integer representations.
+
+use core::fmt;
+use core::num::IntErrorKind;
+
+/// An error which can be returned when parsing a string that is not a valid
+/// integer representation.
+///
+/// This error is carried by the `Invalid` variant of the `Constrained` types'
+/// parse errors. Values that are valid integers but are out of the range's bounds
+/// are reported by their `Lower` and `Greater` variants instead.
+///
+/// If this crate's `std` feature is enabled, this error implements the standard
+/// library's `Error` trait.
+///
+/// # Example
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use core::num::IntErrorKind;
+/// use constrained_int::u8::{ConstrainedU8, ParseU8Error};
+///
+/// type Constrained = ConstrainedU8<1, 254>;
+///
+/// match "12a".parse::<Constrained>() {
+///     Err(ParseU8Error::Invalid(err)) => assert_eq!(err.kind(), &IntErrorKind::InvalidDigit),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseIntError {
+    kind: Kind,
+}
+
+// A subset of `IntErrorKind`, since overflows are reported as range bound violations.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    Empty,
+    InvalidDigit,
+}
+
+impl ParseIntError {
+    /// Creates an error for an empty string.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn empty() -> Self {
+        Self { kind: Kind::Empty }
+    }
+
+    /// Creates an error for a string with an invalid digit.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn invalid_digit() -> Self {
+        Self { kind: Kind::InvalidDigit }
+    }
+
+    /// Outputs the detailed cause of parsing an integer failing.
+    ///
+    /// Only [`Empty`] and [`InvalidDigit`] are ever returned.
+    ///
+    /// [`Empty`]: core::num::IntErrorKind::Empty
+    /// [`InvalidDigit`]: core::num::IntErrorKind::InvalidDigit
+    #[must_use]
+    pub const fn kind(&self) -> &IntErrorKind {
+        match self.kind {
+            Kind::Empty => &IntErrorKind::Empty,
+            Kind::InvalidDigit => &IntErrorKind::InvalidDigit,
+        }
+    }
+}
+
+impl fmt::Display for ParseIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            Kind::Empty => f.write_str("cannot parse integer from empty string"),
+            Kind::InvalidDigit => f.write_str("invalid digit found in string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIntError {}
+
+/// Converts an ASCII byte into a digit of the given radix.
+///
+/// Returns [`None`] if the byte is not a valid digit for the radix.
+#[must_use]
+pub(crate) const fn to_digit(byte: u8, radix: u32) -> Option<u32> {
+    let digit = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return None,
+    } as u32;
+
+    if digit < radix {
+        Some(digit)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,83 @@
+//! Overflow free arithmetics for linear remapping between ranges.
+
+/// Computes `lhs * rhs / div`, rounded down, without overflowing the intermediate
+/// product.
+///
+/// The caller must ensure that `div` is not zero and that the result fits in
+/// `u128`, which holds if `lhs <= div`.
+#[must_use]
+pub(crate) const fn mul_div(lhs: u128, rhs: u128, div: u128) -> u128 {
+    debug_assert!(div != 0, "`div` can't be zero");
+
+    let (hi, lo) = widening_mul(lhs, rhs);
+    let mut quot = 0;
+    let mut rem: u128 = 0;
+    let mut bit = 256;
+
+    // Binary long division of the 256 bit product, the remainder may
+    // temporarily need 129 bits, so the carried out bit is tracked.
+    while bit > 0 {
+        bit -= 1;
+        let next = if bit >= 128 { hi >> (bit - 128) } else { lo >> bit } & 1;
+        let carry = rem >> 127;
+        rem = (rem << 1) | next;
+
+        if carry == 1 || rem >= div {
+            rem = rem.wrapping_sub(div);
+            if bit < 128 {
+                quot |= 1 << bit;
+            }
+        }
+    }
+
+    quot
+}
+
+/// Computes the 256 bit product of `lhs` and `rhs`, as its high and low halves.
+#[must_use]
+const fn widening_mul(lhs: u128, rhs: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (lhs_hi, lhs_lo) = (lhs >> 64, lhs & MASK);
+    let (rhs_hi, rhs_lo) = (rhs >> 64, rhs & MASK);
+
+    let lo_lo = lhs_lo * rhs_lo;
+    let hi_lo = lhs_hi * rhs_lo;
+    let lo_hi = lhs_lo * rhs_hi;
+    let hi_hi = lhs_hi * rhs_hi;
+
+    // Sum of the middle terms' low halves and the carry of the lowest term,
+    // at most three 64 bit values, so it fits in `u128`.
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+
+    let lo = (mid << 64) | (lo_lo & MASK);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mul_div;
+
+    #[test]
+    fn mul_div_small() {
+        for lhs in 0..=20 {
+            for rhs in 0..=20 {
+                for div in 1..=20 {
+                    assert_eq!(mul_div(lhs, rhs, div), lhs * rhs / div);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_wide() {
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(mul_div(u128::MAX - 1, u128::MAX, u128::MAX), u128::MAX - 1);
+        assert_eq!(mul_div(u128::MAX / 2, u128::MAX, u128::MAX), u128::MAX / 2);
+        assert_eq!(mul_div(u128::MAX / 2, 100, u128::MAX), 49);
+        assert_eq!(mul_div(1 << 100, 1 << 100, 1 << 110), 1 << 90);
+        assert_eq!(mul_div(u64::MAX as u128, u64::MAX as u128, u64::MAX as u128), u64::MAX as u128);
+    }
+}
@@ -4,3 +4,35 @@ pub struct Constraints<const T: bool>;
 #[doc(hidden)]
 pub trait Guard {}
 impl Guard for Constraints<true> {}
+
+// Prevents downstream crates from implementing the `BoundedInt` trait.
+#[doc(hidden)]
+pub trait Sealed {}
+
+// This const function is used to enforce constraints for conversions between
+// `Constrained` types of different primitives. Bounds are compared through the
+// sign-aware keys returned by each module's `guard_key`.
+// The constraints are:
+//     - `MIN` must be equal to, or lower than `SMIN`.
+//     - `MAX` must be equal to, or greater than `SMAX`.
+// This ensures that every value of the source range is contained by the target range.
+#[must_use]
+#[inline(always)]
+#[doc(hidden)]
+pub const fn guard_contains(
+    smin: (bool, u128),
+    smax: (bool, u128),
+    min: (bool, u128),
+    max: (bool, u128),
+) -> bool {
+    key_le(min, smin) && key_le(smax, max)
+}
+
+// Compares two sign-aware keys, negative values always compare lower.
+const fn key_le(lhs: (bool, u128), rhs: (bool, u128)) -> bool {
+    if lhs.0 == rhs.0 {
+        lhs.1 <= rhs.1
+    } else {
+        rhs.0
+    }
+}
@@ -0,0 +1,32 @@
+use rand::distributions::uniform::UniformInt;
+
+use crate::BoundedInt;
+
+/// The back-end implementing [`UniformSampler`] for `Constrained` types.
+///
+/// Samples uniformly between two `Constrained` values, and since both values are
+/// contained by the range, so is every sampled value. Users should not need to
+/// use this type directly, see [`Uniform`] and [`Rng::gen_range`] instead.
+///
+/// # Example
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use rand::Rng;
+/// use constrained_int::u128::ConstrainedU128;
+///
+/// type Constrained = ConstrainedU128<1, { u128::MAX - 1 }>;
+///
+/// let low = Constrained::new(10).unwrap();
+/// let high = Constrained::new(20).unwrap();
+///
+/// let value = rand::thread_rng().gen_range(low..=high);
+/// assert!((low..=high).contains(&value));
+/// ```
+///
+/// [`UniformSampler`]: rand::distributions::uniform::UniformSampler
+/// [`Uniform`]: rand::distributions::Uniform
+/// [`Rng::gen_range`]: rand::Rng::gen_range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformConstrained<T: BoundedInt>(pub(crate) UniformInt<T::Int>);
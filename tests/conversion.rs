@@ -0,0 +1,119 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+// Implements tests for conversions between every pair of the provided types,
+// in both directions, under a module named after the first type of the pair.
+macro_rules! tests_conversion {
+    (   { $Int:ty, $md:ident, $Ty:ident, $Err:ident }
+     $(, { $TInt:ty, $tmd:ident, $TTy:ident, $TErr:ident })* $(,)?
+    ) => {
+        mod $md {
+            mod into {
+                $(
+                    tests_conversion_pair! {
+                        $tmd, { $Int, $md, $Ty } => { $TInt, $tmd, $TTy, $TErr }
+                    }
+                )*
+            }
+
+            mod from {
+                $(
+                    tests_conversion_pair! {
+                        $tmd, { $TInt, $tmd, $TTy } => { $Int, $md, $Ty, $Err }
+                    }
+                )*
+            }
+        }
+
+        tests_conversion! { $({ $TInt, $tmd, $TTy, $TErr }),* }
+    };
+    () => {};
+}
+
+// Implements tests for conversions from a source type into a target type.
+macro_rules! tests_conversion_pair {
+    ($name:ident, { $SInt:ty, $smd:ident, $STy:ident } => { $Int:ty, $dmd:ident, $Ty:ident, $Err:ident }) => {
+        mod $name {
+            use constrained_int::$dmd::{$Err, $Ty};
+            use constrained_int::$smd::$STy;
+
+            type SrcTest = $STy<1, 100, 50>;
+            type CnstTest = $Ty<1, 100>;
+
+            #[test]
+            fn from_contained() {
+                let value = $Ty::<0, 100>::from(SrcTest::default());
+                assert_eq!(value.get(), 50);
+
+                let value: $Ty<1, 100> = SrcTest::new_min().into();
+                assert_eq!(value.get(), 1);
+
+                let value: $Ty<1, 101> = SrcTest::new_max().into();
+                assert_eq!(value.get(), 100);
+            }
+
+            #[test]
+            fn try_convert() {
+                assert_eq!(SrcTest::new_max().try_convert::<CnstTest>(), CnstTest::new(100));
+
+                let err = SrcTest::new_max().try_convert::<$Ty<1, 99>>().unwrap_err();
+                assert!(matches!(err, $Err::Greater(_)));
+                assert_eq!(err.value(), Some(100));
+
+                let err = SrcTest::new_min().try_convert::<$Ty<2, 100>>().unwrap_err();
+                assert!(matches!(err, $Err::Lower(_)));
+                assert_eq!(err.value(), Some(1));
+            }
+
+            #[test]
+            fn try_from_bounded() {
+                for value in 1..=100 {
+                    let cnst = CnstTest::try_from(value as $SInt).unwrap();
+                    assert_eq!(cnst.get(), value as $Int);
+                }
+            }
+
+            #[test]
+            fn try_from_unbounded() {
+                assert_eq!(CnstTest::try_from(0 as $SInt), CnstTest::new(0));
+                assert_eq!(CnstTest::try_from(101 as $SInt), CnstTest::new(101));
+
+                // Rejected values are only known if representable by the target primitive.
+                let bound = |value: $SInt| <$Int>::try_from(value).ok();
+                let err = CnstTest::try_from(<$SInt>::MIN).unwrap_err();
+                assert!(matches!(err, $Err::Lower(_)));
+                assert_eq!(err.value(), bound(<$SInt>::MIN));
+                assert!(err.to_string().ends_with(&format!(", found {}", <$SInt>::MIN)));
+                let err = CnstTest::try_from(<$SInt>::MAX).unwrap_err();
+                assert!(matches!(err, $Err::Greater(_)));
+                assert_eq!(err.value(), bound(<$SInt>::MAX));
+                assert!(err.to_string().ends_with(&format!(", found {}", <$SInt>::MAX)));
+            }
+
+            #[test]
+            fn try_from_primitive_bounds() {
+                type FullTest = $Ty<{ <$Int>::MIN }, { <$Int>::MAX - 1 }>;
+                let value = <$SInt>::MIN;
+                match <$Int>::try_from(value) {
+                    Ok(value) => assert_eq!(FullTest::try_from(value).unwrap().get(), value),
+                    Err(_) => assert!(matches!(FullTest::try_from(value), Err($Err::Lower(_)))),
+                }
+            }
+        }
+    };
+}
+
+tests_conversion! {
+    { u8, u8, ConstrainedU8, ConstrainedU8Error },
+    { u16, u16, ConstrainedU16, ConstrainedU16Error },
+    { u32, u32, ConstrainedU32, ConstrainedU32Error },
+    { u64, u64, ConstrainedU64, ConstrainedU64Error },
+    { u128, u128, ConstrainedU128, ConstrainedU128Error },
+    { usize, usize, ConstrainedUsize, ConstrainedUsizeError },
+    { i8, i8, ConstrainedI8, ConstrainedI8Error },
+    { i16, i16, ConstrainedI16, ConstrainedI16Error },
+    { i32, i32, ConstrainedI32, ConstrainedI32Error },
+    { i64, i64, ConstrainedI64, ConstrainedI64Error },
+    { i128, i128, ConstrainedI128, ConstrainedI128Error },
+    { isize, isize, ConstrainedIsize, ConstrainedIsizeError },
+}
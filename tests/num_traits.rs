@@ -0,0 +1,106 @@
+use num_traits::{
+    Bounded, CheckedAdd, CheckedSub, FromPrimitive, NumCast, SaturatingAdd, SaturatingSub,
+    ToPrimitive, WrappingAdd, WrappingSub,
+};
+
+#[test]
+fn bounded() {
+    use constrained_int::u8::ConstrainedU8;
+    type Cnst = ConstrainedU8<3, 10, 5>;
+    assert_eq!(<Cnst as Bounded>::min_value(), Cnst::new_min());
+    assert_eq!(<Cnst as Bounded>::max_value(), Cnst::new_max());
+
+    use constrained_int::i128::ConstrainedI128;
+    type CnstI128 = ConstrainedI128<{ i128::MIN }, 0>;
+    assert_eq!(<CnstI128 as Bounded>::min_value().get(), i128::MIN);
+    assert_eq!(<CnstI128 as Bounded>::max_value().get(), 0);
+}
+
+#[test]
+fn to_primitive() {
+    use constrained_int::i16::ConstrainedI16;
+    let cnst = ConstrainedI16::<-300, 300>::new_min();
+    assert_eq!(cnst.to_i64(), Some(-300));
+    assert_eq!(cnst.to_u64(), None);
+    assert_eq!(cnst.to_i8(), None);
+    assert_eq!(cnst.to_f64(), Some(-300.0));
+
+    use constrained_int::u128::ConstrainedU128;
+    let cnst = ConstrainedU128::<0, { u128::MAX - 1 }>::new_max();
+    assert_eq!(cnst.to_u128(), Some(u128::MAX - 1));
+    assert_eq!(cnst.to_i128(), None);
+    assert_eq!(cnst.to_u64(), None);
+}
+
+#[test]
+fn from_primitive() {
+    use constrained_int::u8::ConstrainedU8;
+    type Cnst = ConstrainedU8<3, 10>;
+    assert_eq!(Cnst::from_i64(3), Cnst::new(3).ok());
+    assert_eq!(Cnst::from_u64(10), Cnst::new(10).ok());
+    assert_eq!(Cnst::from_i64(-1), None);
+    assert_eq!(Cnst::from_u64(2), None);
+    assert_eq!(Cnst::from_u128(u128::MAX), None);
+    assert_eq!(Cnst::from_f64(7.5), Cnst::new(7).ok());
+
+    use constrained_int::i64::ConstrainedI64;
+    type CnstI64 = ConstrainedI64<-5, 5>;
+    assert_eq!(CnstI64::from_i128(-5), CnstI64::new(-5).ok());
+    assert_eq!(CnstI64::from_i128(i128::MIN), None);
+}
+
+#[test]
+fn num_cast() {
+    use constrained_int::i8::ConstrainedI8;
+    type Cnst = ConstrainedI8<-10, 10>;
+    assert_eq!(<Cnst as NumCast>::from(-10i64), Cnst::new(-10).ok());
+    assert_eq!(<Cnst as NumCast>::from(10u128), Cnst::new(10).ok());
+    assert_eq!(<Cnst as NumCast>::from(11u8), None);
+    assert_eq!(<Cnst as NumCast>::from(300i32), None);
+    assert_eq!(<Cnst as NumCast>::from(Cnst::new_max()), Some(Cnst::new_max()));
+}
+
+#[test]
+fn constrained_ops() {
+    use constrained_int::i32::ConstrainedI32;
+    type Cnst = ConstrainedI32<-3, 5>;
+    let (min, max) = (Cnst::new_min(), Cnst::new_max());
+    let one = Cnst::new(1).unwrap();
+
+    assert_eq!(CheckedAdd::checked_add(&max, &one), None);
+    assert_eq!(CheckedAdd::checked_add(&min, &one), Cnst::new(-2).ok());
+    assert_eq!(CheckedSub::checked_sub(&min, &one), None);
+    assert_eq!(CheckedSub::checked_sub(&max, &one), Cnst::new(4).ok());
+    assert_eq!(SaturatingAdd::saturating_add(&max, &one), max);
+    assert_eq!(SaturatingSub::saturating_sub(&min, &one), min);
+    assert_eq!(WrappingAdd::wrapping_add(&max, &one), min);
+    assert_eq!(WrappingSub::wrapping_sub(&min, &one), max);
+}
+
+#[test]
+fn wrapping() {
+    use constrained_int::u16::ConstrainedU16;
+    use constrained_int::Wrapping;
+    type Cnst = ConstrainedU16<1, 100>;
+    type Wrap = Wrapping<Cnst>;
+    let one = Wrapping(Cnst::new_min());
+
+    assert_eq!(<Wrap as Bounded>::min_value(), one);
+    assert_eq!(<Wrap as Bounded>::max_value(), Wrapping(Cnst::new_max()));
+    assert_eq!(Wrap::max_value().wrapping_add(&one), Wrap::min_value());
+    assert_eq!(Wrap::min_value().wrapping_sub(&one), Wrap::max_value());
+}
+
+#[test]
+fn saturating() {
+    use constrained_int::isize::ConstrainedIsize;
+    use constrained_int::Saturating;
+    type Cnst = ConstrainedIsize<-100, 100>;
+    type Sat = Saturating<Cnst>;
+    let one = Saturating(Cnst::new(1).unwrap());
+
+    assert_eq!(<Sat as Bounded>::min_value(), Saturating(Cnst::new_min()));
+    assert_eq!(<Sat as Bounded>::max_value(), Saturating(Cnst::new_max()));
+    assert_eq!(Sat::max_value().saturating_add(&one), Sat::max_value());
+    assert_eq!(Sat::min_value().saturating_sub(&one), Sat::min_value());
+}
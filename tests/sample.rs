@@ -0,0 +1,69 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use rand::distributions::{Distribution, Standard, Uniform};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+#[test]
+fn standard_covers_range() {
+    use constrained_int::i8::ConstrainedI8;
+    type Cnst = ConstrainedI8<-3, 4>;
+
+    let mut seen = [false; 8];
+    for cnst in Standard.sample_iter(rng()).take(1000) {
+        let cnst: Cnst = cnst;
+        seen[(cnst.get() + 3) as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn standard_wide_ranges() {
+    use constrained_int::i128::ConstrainedI128;
+    use constrained_int::u128::ConstrainedU128;
+    type CnstU128 = ConstrainedU128<{ u128::MAX / 2 }, { u128::MAX - 1 }>;
+    type CnstI128 = ConstrainedI128<{ i128::MIN + 1 }, 0>;
+
+    let mut rng = rng();
+    for _ in 0..1000 {
+        let cnst: CnstU128 = rng.gen();
+        assert!(CnstU128::range().contains(&cnst.get()));
+
+        let cnst: CnstI128 = rng.gen();
+        assert!(CnstI128::range().contains(&cnst.get()));
+    }
+}
+
+#[test]
+fn gen_range_between_values() {
+    use constrained_int::u16::ConstrainedU16;
+    type Cnst = ConstrainedU16<10, 1000>;
+    let (low, high) = (Cnst::new(20).unwrap(), Cnst::new(25).unwrap());
+
+    let mut rng = rng();
+    let mut seen = [false; 6];
+    for _ in 0..1000 {
+        let cnst = rng.gen_range(low..=high);
+        seen[(cnst.get() - 20) as usize] = true;
+
+        let cnst = rng.gen_range(low..high);
+        assert!((low..high).contains(&cnst));
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn uniform_distribution() {
+    use constrained_int::isize::ConstrainedIsize;
+    type Cnst = ConstrainedIsize<-50, 50>;
+    let uniform = Uniform::new_inclusive(Cnst::new_min(), Cnst::new_max());
+
+    for cnst in uniform.sample_iter(rng()).take(1000) {
+        assert!(Cnst::range().contains(&cnst.get()));
+    }
+}